@@ -161,7 +161,7 @@ async fn main() -> extended_rust_sdk::error::Result<()> {
 
     // 4. Fetch leverage settings
     println!("Fetching leverage settings...");
-    match private_api.get_leverage(None).await {
+    match private_api.get_leverage(None::<&str>).await {
         Ok(leverages) => {
             for lev in leverages.iter().take(5) {
                 if let Some(max) = lev.max_leverage {
@@ -31,8 +31,7 @@ async fn main() -> extended_rust_sdk::error::Result<()> {
     // 1. Fetch market config (for tick_size, step_size, and L2 config)
     let market_name = "ETH-USD";
     println!("Fetching {} market data...", market_name);
-    let markets = public_api.get_markets().await?;
-    let market = markets.get(market_name).expect("Market not found");
+    let market = public_api.get_market(market_name).await?;
     let trading_config = market.config();
 
     println!("  Tick Size: {}", trading_config.tick_size());
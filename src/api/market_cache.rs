@@ -0,0 +1,133 @@
+//! Shared cache of market metadata.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::RwLock;
+
+use super::PublicApi;
+use crate::error::{ExtendedError, Result};
+use crate::models::Market;
+
+/// Default time markets are cached before being refetched.
+pub const DEFAULT_MARKET_CACHE_TTL: Duration = Duration::from_secs(300);
+
+#[derive(Debug)]
+struct CachedMarkets {
+    markets: Arc<HashMap<String, Market>>,
+    fetched_at: Instant,
+}
+
+/// Market metadata fetched once and reused until it's older than a TTL.
+///
+/// Signing an order needs the market's L2 config (synthetic asset ID and resolution),
+/// which rarely changes, so there's no need for a round-trip per order. Clones share
+/// the same cache, and concurrent callers hitting a stale cache wait for a single
+/// refetch rather than each issuing their own.
+#[derive(Debug, Clone)]
+pub struct MarketCache {
+    api: PublicApi,
+    ttl: Duration,
+    cached: Arc<RwLock<Option<CachedMarkets>>>,
+    /// Names a refetch didn't find, with when it ran, so they aren't refetched for again
+    /// until the TTL has passed.
+    unknown: Arc<Mutex<HashMap<String, Instant>>>,
+}
+
+impl MarketCache {
+    /// Create an empty cache; markets are fetched on first use.
+    ///
+    /// # Arguments
+    /// * `api` - Public API used to fetch markets
+    /// * `ttl` - How long fetched markets stay fresh
+    pub fn new(api: PublicApi, ttl: Duration) -> Self {
+        Self {
+            api,
+            ttl,
+            cached: Arc::new(RwLock::new(None)),
+            unknown: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Get the time markets stay fresh.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Get all markets, refetching them if the cache is empty or stale.
+    pub async fn markets(&self) -> Result<Arc<HashMap<String, Market>>> {
+        if let Some(cached) = self.cached.read().await.as_ref() {
+            if cached.fetched_at.elapsed() < self.ttl {
+                return Ok(cached.markets.clone());
+            }
+        }
+
+        let mut cached = self.cached.write().await;
+        // Another caller may have refreshed while we waited for the lock
+        if let Some(cached) = cached.as_ref() {
+            if cached.fetched_at.elapsed() < self.ttl {
+                return Ok(cached.markets.clone());
+            }
+        }
+        let markets = Arc::new(self.api.get_markets().await?);
+        *cached = Some(CachedMarkets {
+            markets: markets.clone(),
+            fetched_at: Instant::now(),
+        });
+        Ok(markets)
+    }
+
+    /// Get a market by name.
+    ///
+    /// A market missing from a cached set triggers one refetch, so newly listed
+    /// markets are picked up before the TTL runs out. Fails with `ExtendedError::Api`
+    /// (code "404") if the market still isn't found; the name is then remembered as
+    /// unknown for a TTL, so looking it up again (e.g. a typo in a loop) doesn't refetch.
+    ///
+    /// # Arguments
+    /// * `name` - Market name (e.g., "BTC-USD")
+    pub async fn market(&self, name: impl AsRef<str>) -> Result<Market> {
+        let name = name.as_ref();
+        let not_found = || ExtendedError::Api {
+            code: "404".to_string(),
+            message: format!("market {} not found", name),
+        };
+        if let Some(market) = self.markets().await?.get(name) {
+            return Ok(market.clone());
+        }
+        if self.is_known_unknown(name) {
+            return Err(not_found());
+        }
+
+        let markets = self.refresh().await?;
+        let mut unknown = self.unknown.lock().unwrap_or_else(|e| e.into_inner());
+        match markets.get(name) {
+            Some(market) => {
+                unknown.remove(name);
+                Ok(market.clone())
+            }
+            None => {
+                unknown.retain(|_, checked_at| checked_at.elapsed() < self.ttl);
+                unknown.insert(name.to_string(), Instant::now());
+                Err(not_found())
+            }
+        }
+    }
+
+    fn is_known_unknown(&self, name: &str) -> bool {
+        let unknown = self.unknown.lock().unwrap_or_else(|e| e.into_inner());
+        unknown.get(name).is_some_and(|checked_at| checked_at.elapsed() < self.ttl)
+    }
+
+    /// Refetch all markets now, regardless of the TTL.
+    pub async fn refresh(&self) -> Result<Arc<HashMap<String, Market>>> {
+        let mut cached = self.cached.write().await;
+        let markets = Arc::new(self.api.get_markets().await?);
+        *cached = Some(CachedMarkets {
+            markets: markets.clone(),
+            fetched_at: Instant::now(),
+        });
+        Ok(markets)
+    }
+}
@@ -1,7 +1,11 @@
 //! API endpoint implementations.
 
+mod market_cache;
 mod private;
 mod public;
 
-pub use private::PrivateApi;
+pub use market_cache::{MarketCache, DEFAULT_MARKET_CACHE_TTL};
+pub use private::{
+    Dashboard, DeadManSwitchGuard, PositionMismatch, PrivateApi, ReconciliationReport,
+};
 pub use public::PublicApi;
@@ -1,28 +1,282 @@
 //! Private API endpoints (authentication required).
 
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use rust_decimal::Decimal;
+use tokio::sync::RwLock;
+
 use crate::client::HttpClient;
-use crate::error::Result;
+use crate::error::{ExtendedError, Result};
+use crate::signing::{get_private_key_from_eth_signature, EthDerivedKey, SecretKey};
 use crate::models::{
-    AccountInfo, AssetOperation, Balance, CreateOrderRequest, MarketFee,
+    AccountInfo, AssetOperation, AutoCorrect, AvgFillPrice, Balance, BridgeConfig, BridgeQuote,
+    BridgeQuoteRequest, CreateOrderRequest, MarketFee,
     FundingPayment, GetFundingHistoryParams, GetOrdersParams,
-    GetPositionHistoryParams, GetPositionsParams, GetTradesParams, Leverage,
-    MassCancelParams, MassCancelResponse, Order, PaginatedResponse, PlacedOrderResponse,
-    Position, PositionHistory, SpotBalance, SpotBalances, Trade, Transfer, TransferRequest,
+    GetPositionHistoryParams, GetPositionsParams, GetTradesParams, Leverage, Market,
+    MassCancelParams, MassCancelResponse, Order, OrderStatus, PaginatedResponse, PaginationParams,
+    PlacedOrderResponse,
+    Position, PositionHistory, PositionSide, SpotBalance, SpotBalances,
+    Trade, TradeRole, Trades, Transfer, TransferRequest,
     UpdateLeverageRequest, Withdrawal, WithdrawalRequest,
 };
 
+/// Maximum number of requests issued concurrently by multi-request helpers.
+const MAX_CONCURRENT_REQUESTS: usize = 5;
+
+/// Consecutive "not found" responses tolerated while polling a just-placed order.
+const ORDER_NOT_FOUND_RETRIES: u32 = 3;
+
+/// Fee rates keyed by market name.
+type FeesByMarket = Arc<HashMap<String, MarketFee>>;
+
+/// Snapshot of the account for dashboards, fetched concurrently by `PrivateApi::fetch_dashboard`.
+///
+/// Each section carries its own result so one failing call doesn't hide the others.
+#[derive(Debug)]
+pub struct Dashboard {
+    /// Account information.
+    pub account_info: Result<AccountInfo>,
+    /// Account balance.
+    pub balance: Result<Balance>,
+    /// Open positions.
+    pub positions: Result<Vec<Position>>,
+    /// Open orders.
+    pub open_orders: Result<Vec<Order>>,
+    /// Leverage settings for all markets.
+    pub leverage: Result<Vec<Leverage>>,
+}
+
+impl Dashboard {
+    /// Check if every section loaded successfully.
+    pub fn is_complete(&self) -> bool {
+        self.errors().is_empty()
+    }
+
+    /// Get the errors of the sections that failed to load.
+    pub fn errors(&self) -> Vec<&ExtendedError> {
+        [
+            self.account_info.as_ref().err(),
+            self.balance.as_ref().err(),
+            self.positions.as_ref().err(),
+            self.open_orders.as_ref().err(),
+            self.leverage.as_ref().err(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// Position size that differs between the local view and the exchange.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionMismatch {
+    /// Market name.
+    pub market: String,
+    /// Signed size in the local view (zero if absent).
+    pub local_size: Decimal,
+    /// Signed size on the exchange (zero if no open position).
+    pub exchange_size: Decimal,
+}
+
+/// Differences between a local order/position view and the exchange, built by
+/// `PrivateApi::reconcile`.
+#[derive(Debug, Clone)]
+pub struct ReconciliationReport {
+    /// Open orders on the exchange that the local view doesn't know about.
+    pub unknown_orders: Vec<Order>,
+    /// Locally tracked order IDs that aren't open on the exchange (filled, cancelled
+    /// or never placed).
+    pub missing_orders: Vec<String>,
+    /// Markets whose position size differs, sorted by market name.
+    pub position_mismatches: Vec<PositionMismatch>,
+}
+
+impl ReconciliationReport {
+    fn new(
+        open_orders: Vec<Order>,
+        positions: &[Position],
+        local_orders: &[String],
+        local_positions: &HashMap<String, Decimal>,
+    ) -> Self {
+        // An order is known if the local view tracks either its internal or external ID
+        let local: HashSet<&str> = local_orders.iter().map(String::as_str).collect();
+        let mut seen = HashSet::new();
+        let mut unknown_orders = Vec::new();
+        for order in open_orders {
+            let ids = [Some(order.id.as_str()), order.external_id.as_deref()];
+            let matched: Vec<&str> =
+                ids.into_iter().flatten().filter(|id| local.contains(id)).collect();
+            if matched.is_empty() {
+                unknown_orders.push(order);
+            } else {
+                seen.extend(matched.into_iter().map(str::to_string));
+            }
+        }
+        let missing_orders = local_orders
+            .iter()
+            .filter(|id| !seen.contains(id.as_str()))
+            .cloned()
+            .collect();
+
+        let mut exchange: HashMap<&str, Decimal> = HashMap::new();
+        for position in positions {
+            let size = match position.side {
+                PositionSide::Long => position.size.abs(),
+                PositionSide::Short => -position.size.abs(),
+            };
+            *exchange.entry(position.market.as_str()).or_default() += size;
+        }
+        let markets: HashSet<&str> = exchange
+            .keys()
+            .copied()
+            .chain(local_positions.keys().map(String::as_str))
+            .collect();
+        let mut position_mismatches: Vec<PositionMismatch> = markets
+            .into_iter()
+            .filter_map(|market| {
+                let local_size = local_positions.get(market).copied().unwrap_or_default();
+                let exchange_size = exchange.get(market).copied().unwrap_or_default();
+                (local_size != exchange_size).then(|| PositionMismatch {
+                    market: market.to_string(),
+                    local_size,
+                    exchange_size,
+                })
+            })
+            .collect();
+        position_mismatches.sort_by(|a, b| a.market.cmp(&b.market));
+
+        Self {
+            unknown_orders,
+            missing_orders,
+            position_mismatches,
+        }
+    }
+
+    /// Check if the local view matches the exchange.
+    pub fn is_consistent(&self) -> bool {
+        self.unknown_orders.is_empty()
+            && self.missing_orders.is_empty()
+            && self.position_mismatches.is_empty()
+    }
+}
+
+/// Keeps the dead man's switch armed until dropped, returned by
+/// `PrivateApi::spawn_dead_man_switch`.
+///
+/// Dropping the guard (including when the process exits) stops the refreshes, so the
+/// exchange cancels all orders once the last countdown runs out. The same happens if
+/// refreshes keep failing, so check [`consecutive_failures`](Self::consecutive_failures)
+/// (failures are also logged with the `tracing` feature).
+#[derive(Debug)]
+pub struct DeadManSwitchGuard {
+    task: tokio::task::JoinHandle<()>,
+    status: Arc<std::sync::Mutex<RefreshStatus>>,
+}
+
+/// Outcome of the dead man's switch refreshes since the last successful one.
+#[derive(Debug, Default)]
+struct RefreshStatus {
+    consecutive_failures: u32,
+    last_error: Option<String>,
+}
+
+impl DeadManSwitchGuard {
+    /// Check if the refresh task is still running.
+    pub fn is_active(&self) -> bool {
+        !self.task.is_finished()
+    }
+
+    /// Get the number of refreshes that failed since the last successful one.
+    pub fn consecutive_failures(&self) -> u32 {
+        self.status().consecutive_failures
+    }
+
+    /// Get the error of the latest refresh, if it failed.
+    pub fn last_error(&self) -> Option<String> {
+        self.status().last_error.clone()
+    }
+
+    fn status(&self) -> std::sync::MutexGuard<'_, RefreshStatus> {
+        self.status.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for DeadManSwitchGuard {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
 /// Private API for Extended Exchange.
 ///
 /// These endpoints require authentication via API key.
 #[derive(Debug, Clone)]
 pub struct PrivateApi {
     client: HttpClient,
+    /// Fee rates by market, fetched on first use (shared between clones).
+    fees: Arc<RwLock<Option<FeesByMarket>>>,
+    /// External IDs submitted so far, when tracking is enabled (shared between clones).
+    external_ids: Option<Arc<std::sync::Mutex<HashSet<String>>>>,
 }
 
 impl PrivateApi {
     /// Create a new private API instance.
     pub fn new(client: HttpClient) -> Self {
-        Self { client }
+        Self {
+            client,
+            fees: Arc::new(RwLock::new(None)),
+            external_ids: None,
+        }
+    }
+
+    /// Enable duplicate external ID detection for orders submitted through this API.
+    ///
+    /// When enabled, every order submission (`create_order`, `replace_order`,
+    /// `create_orders` and the helpers built on them) records the order's external ID
+    /// and rejects any ID already submitted during the lifetime of this API and its
+    /// clones, catching copy-paste mistakes (e.g. two grid levels sharing an ID) before
+    /// the exchange does. The ID stays recorded even if submission fails, since a
+    /// timed-out request may still have reached the exchange; use `forget_external_id`
+    /// to retry.
+    pub fn with_external_id_tracking(mut self) -> Self {
+        self.external_ids = Some(Arc::new(std::sync::Mutex::new(HashSet::new())));
+        self
+    }
+
+    /// Record an external ID as used, failing if it was already used.
+    ///
+    /// Always succeeds when tracking is disabled.
+    pub fn check_external_id(&self, external_id: &str) -> Result<()> {
+        let Some(ids) = &self.external_ids else {
+            return Ok(());
+        };
+        let mut ids = ids.lock().unwrap_or_else(|e| e.into_inner());
+        if !ids.insert(external_id.to_string()) {
+            return Err(ExtendedError::OrderValidation(format!(
+                "Duplicate external ID: {} was already submitted by this client",
+                external_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Forget a recorded external ID so it can be submitted again
+    /// (e.g. after confirming the first attempt never reached the exchange).
+    pub fn forget_external_id(&self, external_id: &str) {
+        if let Some(ids) = &self.external_ids {
+            ids.lock().unwrap_or_else(|e| e.into_inner()).remove(external_id);
+        }
+    }
+
+    /// Clear all recorded external IDs.
+    pub fn clear_external_ids(&self) {
+        if let Some(ids) = &self.external_ids {
+            ids.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        }
     }
 
     // ========== Account Endpoints ==========
@@ -37,6 +291,36 @@ impl PrivateApi {
         Ok(resp.data)
     }
 
+    /// Register a Stark key with the exchange (onboarding).
+    ///
+    /// Submits the request built by [`EthDerivedKey::registration`] to the
+    /// `auth/onboard` endpoint: the Stark public key with its L2 signature, and the
+    /// signed `AccountCreation` message with the Ethereum signature the key was derived
+    /// from. Before submitting, the key is re-derived from the Ethereum signature and
+    /// compared with the signer, so a key that doesn't belong to the signature is
+    /// rejected locally.
+    ///
+    /// # Arguments
+    /// * `derived` - Key from `derive_stark_key_from_eth_key` or
+    ///   `derive_stark_key_from_mnemonic`
+    pub async fn register_stark_key(&self, derived: &EthDerivedKey) -> Result<AccountInfo> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            data: AccountInfo,
+        }
+
+        let key = get_private_key_from_eth_signature(&derived.eth_signature)?;
+        if SecretKey::new(key) != *derived.signer.secret_key() {
+            return Err(ExtendedError::Signing(
+                "Stark key was not derived from the given Ethereum signature".to_string(),
+            ));
+        }
+
+        let req = derived.registration()?;
+        let resp: Response = self.client.post_unversioned("auth/onboard", &req).await?;
+        Ok(resp.data)
+    }
+
     /// Get account balance.
     pub async fn get_balance(&self) -> Result<Balance> {
         #[derive(serde::Deserialize)]
@@ -100,6 +384,112 @@ impl PrivateApi {
         Ok(resp.data)
     }
 
+    /// Get the account's fee rates for a market.
+    ///
+    /// Fee tiers rarely change, so rates are fetched with `get_fees` on first use and
+    /// cached for the lifetime of this instance (and its clones); call `refresh_fees`
+    /// after a tier change.
+    ///
+    /// # Arguments
+    /// * `market` - Market name (e.g., "BTC-USD")
+    pub async fn market_fee(&self, market: impl AsRef<str>) -> Result<MarketFee> {
+        let market = market.as_ref();
+        let cached = self.fees.read().await.clone();
+        let fees = match cached {
+            Some(fees) => fees,
+            None => {
+                let mut cached = self.fees.write().await;
+                // Another caller may have fetched while we waited for the lock
+                match cached.as_ref() {
+                    Some(fees) => fees.clone(),
+                    None => cached.insert(self.fetch_fees().await?).clone(),
+                }
+            }
+        };
+        fees.get(market).cloned().ok_or_else(|| {
+            ExtendedError::InvalidParameter(format!("no fee rates for market {}", market))
+        })
+    }
+
+    /// Refetch the cached fee rates used by `market_fee` and `estimate_order_fee`.
+    pub async fn refresh_fees(&self) -> Result<()> {
+        let mut cached = self.fees.write().await;
+        *cached = Some(self.fetch_fees().await?);
+        Ok(())
+    }
+
+    async fn fetch_fees(&self) -> Result<FeesByMarket> {
+        let fees = self.get_fees().await?;
+        let by_market = fees.into_iter().map(|fee| (fee.get_market(), fee)).collect();
+        Ok(Arc::new(by_market))
+    }
+
+    /// Estimate the fee an order would pay at the account's fee tier.
+    ///
+    /// Use the resulting rate (`MarketFee::rate`) with `OrderBuilder::fee` so the signed
+    /// fee matches the account's tier rather than `DEFAULT_FEE_RATE`.
+    ///
+    /// # Arguments
+    /// * `market` - Market name (e.g., "BTC-USD")
+    /// * `price` - Order price
+    /// * `quantity` - Order quantity
+    /// * `is_maker` - Whether the order will rest on the book (e.g. post-only)
+    pub async fn estimate_order_fee(
+        &self,
+        market: impl AsRef<str>,
+        price: Decimal,
+        quantity: Decimal,
+        is_maker: bool,
+    ) -> Result<Decimal> {
+        let market = market.as_ref();
+        Ok(self.market_fee(market).await?.estimate(price, quantity, is_maker))
+    }
+
+    /// Fetch account info, balance, positions, open orders and leverage concurrently.
+    ///
+    /// All five requests are issued at once, so the dashboard loads in a single
+    /// round-trip instead of five sequential ones. Failures are reported per section;
+    /// an error is only returned when every request failed (e.g. bad API key or no network).
+    pub async fn fetch_dashboard(&self) -> Result<Dashboard> {
+        let results = tokio::join!(
+            self.get_account_info(),
+            self.get_balance(),
+            self.get_positions(None),
+            self.get_open_orders(None),
+            self.get_leverage(None::<&str>),
+        );
+
+        match results {
+            (Err(_), Err(e), Err(_), Err(_), Err(_)) => Err(e),
+            (account_info, balance, positions, open_orders, leverage) => Ok(Dashboard {
+                account_info,
+                balance,
+                positions,
+                open_orders,
+                leverage,
+            }),
+        }
+    }
+
+    /// Compare a local order/position view against the exchange.
+    ///
+    /// Open orders and positions are fetched concurrently. Local orders may be tracked by
+    /// internal or external ID. Position sizes are signed (positive long, negative
+    /// short); a market missing on either side counts as flat.
+    ///
+    /// # Arguments
+    /// * `local_orders` - IDs of the orders the local view believes are open
+    /// * `local_positions` - Signed position size per market in the local view
+    pub async fn reconcile(
+        &self,
+        local_orders: &[String],
+        local_positions: &HashMap<String, Decimal>,
+    ) -> Result<ReconciliationReport> {
+        let (open_orders, positions) =
+            tokio::try_join!(self.get_open_orders(None), self.get_positions(None))?;
+        Ok(ReconciliationReport::new(open_orders, &positions, local_orders, local_positions))
+    }
+
     // ========== Position Endpoints ==========
 
     /// Get open positions.
@@ -120,6 +510,18 @@ impl PrivateApi {
         Ok(resp.data)
     }
 
+    /// Get the open position in a market, or `None` if there is none.
+    ///
+    /// # Arguments
+    /// * `market` - Market name (e.g., "BTC-USD")
+    pub async fn get_position(&self, market: impl AsRef<str>) -> Result<Option<Position>> {
+        let market = market.as_ref();
+        let positions = self
+            .get_positions(Some(GetPositionsParams::new().with_market(market)))
+            .await?;
+        Ok(positions.into_iter().find(|p| p.market == market))
+    }
+
     /// Get position history.
     ///
     /// # Arguments
@@ -134,13 +536,34 @@ impl PrivateApi {
             .await
     }
 
+    /// Stream position history, fetching further pages as the stream is consumed.
+    ///
+    /// # Arguments
+    /// * `params` - Optional filter parameters; `limit` sets the page size
+    pub fn position_history_stream(
+        &self,
+        params: Option<GetPositionHistoryParams>,
+    ) -> impl Stream<Item = Result<PositionHistory>> + '_ {
+        let params = params.unwrap_or_default();
+        paginate(params.cursor, move |cursor| {
+            let params = GetPositionHistoryParams { cursor, ..params.clone() };
+            self.get_position_history(Some(params))
+        })
+    }
+
     // ========== Leverage Endpoints ==========
 
     /// Get current leverage settings.
     ///
+    /// Pass `None` to get the leverage for every market; this pairs with
+    /// `set_leverage_all` for account-wide leverage setup.
+    ///
     /// # Arguments
     /// * `market` - Optional market filter
-    pub async fn get_leverage(&self, market: Option<&str>) -> Result<Vec<Leverage>> {
+    pub async fn get_leverage(
+        &self,
+        market: Option<impl AsRef<str>>,
+    ) -> Result<Vec<Leverage>> {
         #[derive(serde::Deserialize)]
         struct Response {
             data: Vec<Leverage>,
@@ -152,6 +575,7 @@ impl PrivateApi {
             market: Option<&'a str>,
         }
 
+        let market = market.as_ref().map(AsRef::as_ref);
         let resp: Response = self
             .client
             .get_with_query("user/leverage", &Params { market })
@@ -164,7 +588,12 @@ impl PrivateApi {
     /// # Arguments
     /// * `market` - Market name
     /// * `leverage` - New leverage value
-    pub async fn update_leverage(&self, market: &str, leverage: u32) -> Result<Leverage> {
+    pub async fn update_leverage(
+        &self,
+        market: impl AsRef<str>,
+        leverage: u32,
+    ) -> Result<Leverage> {
+        let market = market.as_ref();
         #[derive(serde::Deserialize)]
         struct Response {
             data: Leverage,
@@ -179,10 +608,44 @@ impl PrivateApi {
         Ok(resp.data)
     }
 
+    /// Set the same leverage on every market.
+    ///
+    /// Fetches the market list from `get_leverage(None)` and updates each market with
+    /// bounded concurrency. Returns one `(market, result)` entry per market, sorted by
+    /// market name, so a partial failure doesn't hide the markets that succeeded.
+    ///
+    /// # Arguments
+    /// * `leverage` - New leverage value for all markets
+    pub async fn set_leverage_all(
+        &self,
+        leverage: u32,
+    ) -> Result<Vec<(String, Result<Leverage>)>> {
+        let markets: Vec<String> = self
+            .get_leverage(None::<&str>)
+            .await?
+            .into_iter()
+            .map(|l| l.market)
+            .collect();
+
+        let mut results: Vec<(String, Result<Leverage>)> = stream::iter(markets)
+            .map(|market| async move {
+                let result = self.update_leverage(&market, leverage).await;
+                (market, result)
+            })
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .collect()
+            .await;
+        results.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(results)
+    }
+
     // ========== Order Endpoints ==========
 
     /// Create a new order.
     ///
+    /// With `with_external_id_tracking` enabled, an order reusing an external ID is
+    /// rejected with `ExtendedError::OrderValidation` before it's sent.
+    ///
     /// # Arguments
     /// * `request` - Order creation request (must be signed)
     ///
@@ -212,15 +675,108 @@ impl PrivateApi {
     /// # }
     /// ```
     pub async fn create_order(&self, request: CreateOrderRequest) -> Result<PlacedOrderResponse> {
+        self.check_external_id(&request.id)?;
+        self.post_order(&request).await
+    }
+
+    /// Post an order without checking its external ID.
+    async fn post_order(&self, request: &CreateOrderRequest) -> Result<PlacedOrderResponse> {
         #[derive(serde::Deserialize)]
         struct Response {
             data: PlacedOrderResponse,
         }
 
-        let resp: Response = self.client.post("user/order", &request).await?;
+        let resp: Response = self.client.post("user/order", request).await?;
         Ok(resp.data)
     }
 
+    /// Sign and submit an order, auto-correcting it once if rejected as invalid.
+    ///
+    /// If the exchange rejects the order with a validation error (e.g. off-tick price),
+    /// price and quantity are rounded to the market's constraints with
+    /// `CreateOrderRequest::round_to_market`, the order is re-signed and submitted
+    /// exactly once more. The original error is returned if rounding changes nothing;
+    /// the retry's error is returned if it also fails. A duplicate external ID (with
+    /// tracking enabled) fails straight away, without submitting anything.
+    ///
+    /// This is opt-in because correcting the order changes what was asked for. By
+    /// default (`AutoCorrect::Rounding`) only tick/step rounding is applied; pass
+    /// `AutoCorrect::RoundingAndMinSize` to also raise a quantity below the minimum
+    /// order size to it.
+    ///
+    /// # Arguments
+    /// * `request` - Unsigned order (straight from `OrderBuilder::build`)
+    /// * `market` - Market the order is for
+    /// * `correction` - Which corrections may be applied
+    /// * `sign` - Signs an order, e.g. `|order| client.sign_order(order, &market)`
+    pub async fn create_order_auto_correct<F>(
+        &self,
+        request: CreateOrderRequest,
+        market: &Market,
+        correction: AutoCorrect,
+        sign: F,
+    ) -> Result<PlacedOrderResponse>
+    where
+        F: Fn(CreateOrderRequest) -> Result<CreateOrderRequest>,
+    {
+        let signed = sign(request.clone())?;
+        let id = signed.id.clone();
+        // Checked up front so only a rejection by the exchange leads to a retry
+        self.check_external_id(&id)?;
+        let err = match self.post_order(&signed).await {
+            Err(err @ ExtendedError::OrderValidation(_)) => err,
+            other => return other,
+        };
+
+        let mut corrected = request;
+        if corrected.round_to_market(market, correction).is_unchanged() {
+            return Err(err);
+        }
+        let signed = sign(corrected)?;
+        // The exchange rejected the first attempt, so its ID can be reused
+        self.forget_external_id(&id);
+        self.create_order(signed).await
+    }
+
+    /// Atomically replace an open order with a new one.
+    ///
+    /// Sets `cancel_id` on the request and posts it. The exchange cancels the old order
+    /// and places the new one as a single edit: if the old order can't be cancelled
+    /// (e.g. it was already filled), the new order is rejected as well.
+    ///
+    /// # Arguments
+    /// * `old_external_id` - External ID of the order to replace (the `id` it was
+    ///   submitted with), not the exchange's internal order ID
+    /// * `request` - Replacement order (must be signed; `cancel_id` isn't signed, so it
+    ///   can be set after signing)
+    pub async fn replace_order(
+        &self,
+        old_external_id: &str,
+        mut request: CreateOrderRequest,
+    ) -> Result<PlacedOrderResponse> {
+        request.cancel_id = Some(old_external_id.to_string());
+        self.create_order(request).await
+    }
+
+    /// Create several orders in one call.
+    ///
+    /// The API has no batch endpoint, so orders are posted individually with bounded
+    /// concurrency. Returns one result per order, in the same order as `requests`, so
+    /// a rejected order doesn't hide the ones that were placed.
+    ///
+    /// # Arguments
+    /// * `requests` - Order creation requests (each must be signed)
+    pub async fn create_orders(
+        &self,
+        requests: Vec<CreateOrderRequest>,
+    ) -> Vec<Result<PlacedOrderResponse>> {
+        stream::iter(requests)
+            .map(|request| self.create_order(request))
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .collect()
+            .await
+    }
+
     /// Cancel an order by internal ID.
     ///
     /// # Arguments
@@ -281,6 +837,103 @@ impl PrivateApi {
         Ok(resp.data)
     }
 
+    /// Cancel all open orders matching a predicate.
+    ///
+    /// Fetches open orders, filters them with `predicate`, and cancels the matches in a
+    /// single mass-cancel request. Returns the number of orders cancelled.
+    ///
+    /// # Arguments
+    /// * `predicate` - Returns true for orders that should be cancelled
+    ///
+    /// # Example
+    /// ```ignore
+    /// // Cancel all buys below 3000
+    /// let n = api
+    ///     .cancel_orders_where(|o| o.side == OrderSide::Buy && o.price < dec!(3000))
+    ///     .await?;
+    /// ```
+    pub async fn cancel_orders_where<F>(&self, predicate: F) -> Result<u32>
+    where
+        F: Fn(&Order) -> bool,
+    {
+        let order_ids: Vec<String> = self
+            .get_open_orders(None)
+            .await?
+            .into_iter()
+            .filter(|order| predicate(order))
+            .map(|order| order.id)
+            .collect();
+
+        if order_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let resp = self
+            .mass_cancel(Some(MassCancelParams::for_orders(order_ids)))
+            .await?;
+        Ok(resp.cancelled_count)
+    }
+
+    /// Cancel all open orders, optionally in one market, and wait until they're gone.
+    ///
+    /// Snapshots the open orders, issues a mass cancel for the scope, then polls the
+    /// open orders until none of the snapshotted ones remain. Orders placed after the
+    /// snapshot don't hold up the confirmation (and aren't in the result), so a strategy
+    /// quoting concurrently can't keep it polling forever.
+    ///
+    /// Returns the IDs of the confirmed-cancelled orders. Fails with
+    /// `ExtendedError::Timeout` if some are still open after `timeout`.
+    ///
+    /// # Arguments
+    /// * `market` - Market to cancel orders in, or `None` for all markets
+    /// * `poll_interval` - Time between open-order checks
+    /// * `timeout` - Maximum time to wait
+    pub async fn cancel_all_and_confirm(
+        &self,
+        market: Option<impl AsRef<str>>,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Vec<String>> {
+        let market = market.as_ref().map(|m| m.as_ref().to_string());
+        let scope = GetOrdersParams {
+            market: market.clone(),
+            ..Default::default()
+        };
+        let cancelled: Vec<String> = self
+            .get_open_orders(Some(scope.clone()))
+            .await?
+            .into_iter()
+            .map(|order| order.id)
+            .collect();
+
+        let params = MassCancelParams {
+            market,
+            ..Default::default()
+        };
+        self.mass_cancel(Some(params)).await?;
+
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut pending: HashSet<&str> = cancelled.iter().map(String::as_str).collect();
+        while !pending.is_empty() {
+            if tokio::time::Instant::now() + poll_interval > deadline {
+                return Err(ExtendedError::Timeout(format!(
+                    "{} cancelled orders still open after {:?}",
+                    pending.len(),
+                    timeout
+                )));
+            }
+            tokio::time::sleep(poll_interval).await;
+            let open: HashSet<String> = self
+                .get_open_orders(Some(scope.clone()))
+                .await?
+                .into_iter()
+                .map(|order| order.id)
+                .collect();
+            pending.retain(|id| open.contains(*id));
+        }
+        Ok(cancelled)
+    }
+
     /// Get open orders.
     ///
     /// # Arguments
@@ -313,6 +966,44 @@ impl PrivateApi {
             .await
     }
 
+    /// Stream order history, fetching further pages as the stream is consumed.
+    ///
+    /// # Arguments
+    /// * `params` - Optional filter parameters; `limit` sets the page size
+    pub fn orders_history_stream(
+        &self,
+        params: Option<GetOrdersParams>,
+    ) -> impl Stream<Item = Result<Order>> + '_ {
+        let params = params.unwrap_or_default();
+        paginate(params.cursor, move |cursor| {
+            let params = GetOrdersParams { cursor, ..params.clone() };
+            self.get_orders_history(Some(params))
+        })
+    }
+
+    /// Get the most recent filled orders, across history pages, up to `limit` orders.
+    ///
+    /// Stops fetching as soon as `limit` orders are collected, even mid-page.
+    ///
+    /// # Arguments
+    /// * `market` - Market to filter by, or `None` for all markets
+    /// * `limit` - Maximum number of orders to return
+    pub async fn get_filled_orders(
+        &self,
+        market: Option<impl AsRef<str>>,
+        limit: usize,
+    ) -> Result<Vec<Order>> {
+        let params = GetOrdersParams {
+            market: market.map(|m| m.as_ref().to_string()),
+            status: Some(OrderStatus::Filled),
+            ..Default::default()
+        };
+        self.orders_history_stream(Some(params))
+            .take(limit)
+            .try_collect()
+            .await
+    }
+
     /// Get order by internal ID.
     ///
     /// # Arguments
@@ -347,6 +1038,78 @@ impl PrivateApi {
         Ok(resp.data)
     }
 
+    /// Poll an order until it reaches a terminal status (filled, cancelled, rejected
+    /// or expired).
+    ///
+    /// A just-placed order may not be visible yet, so up to three consecutive "not
+    /// found" responses are retried. Fails with `ExtendedError::Timeout` if the order
+    /// is still active after `timeout`.
+    ///
+    /// # Arguments
+    /// * `order_id` - Internal order ID
+    /// * `poll_interval` - Time between status checks
+    /// * `timeout` - Maximum time to wait
+    pub async fn wait_for_order(
+        &self,
+        order_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Order> {
+        self.poll_order(order_id, poll_interval, timeout, |_| false).await
+    }
+
+    /// Poll an order until at least `quantity` is filled or it reaches a terminal status.
+    ///
+    /// Like `wait_for_order`, but returns as soon as a partial fill reaches `quantity`.
+    ///
+    /// # Arguments
+    /// * `order_id` - Internal order ID
+    /// * `quantity` - Filled quantity to wait for
+    /// * `poll_interval` - Time between status checks
+    /// * `timeout` - Maximum time to wait
+    pub async fn wait_for_fill(
+        &self,
+        order_id: &str,
+        quantity: Decimal,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Order> {
+        let filled = |order: &Order| order.filled_quantity.is_some_and(|q| q >= quantity);
+        self.poll_order(order_id, poll_interval, timeout, filled).await
+    }
+
+    async fn poll_order(
+        &self,
+        order_id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+        done: impl Fn(&Order) -> bool,
+    ) -> Result<Order> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut not_found = 0;
+        loop {
+            let status = match self.get_order(order_id).await {
+                Ok(order) if order.status.is_terminal() || done(&order) => return Ok(order),
+                Ok(order) => {
+                    not_found = 0;
+                    format!("{:?}", order.status)
+                }
+                Err(e) if e.is_not_found() && not_found < ORDER_NOT_FOUND_RETRIES => {
+                    not_found += 1;
+                    "not found".to_string()
+                }
+                Err(e) => return Err(e),
+            };
+            if tokio::time::Instant::now() + poll_interval > deadline {
+                return Err(ExtendedError::Timeout(format!(
+                    "order {} still {} after {:?}",
+                    order_id, status, timeout
+                )));
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
     // ========== Trade Endpoints ==========
 
     /// Get trade history (fills).
@@ -361,6 +1124,111 @@ impl PrivateApi {
         self.client.get_with_query("user/trades", &params).await
     }
 
+    /// Stream trade history, fetching further pages as the stream is consumed.
+    ///
+    /// Unlike `get_all_trades`, nothing is buffered beyond the current page, so
+    /// callers can stop early (e.g. with `take_while`) without fetching the rest.
+    ///
+    /// # Arguments
+    /// * `params` - Optional filter parameters; `limit` sets the page size
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// # async fn example(api: &extended_rust_sdk::api::PrivateApi) {
+    /// let mut trades = std::pin::pin!(api.trades_stream(None));
+    /// while let Some(trade) = trades.next().await {
+    ///     println!("{:?}", trade);
+    /// }
+    /// # }
+    /// ```
+    pub fn trades_stream(
+        &self,
+        params: Option<GetTradesParams>,
+    ) -> impl Stream<Item = Result<Trade>> + '_ {
+        let params = params.unwrap_or_default();
+        paginate(params.cursor, move |cursor| {
+            let params = GetTradesParams { cursor, ..params.clone() };
+            self.get_trades(Some(params))
+        })
+    }
+
+    /// Get trade history filtered by liquidity role (maker or taker).
+    ///
+    /// The API has no role filter, so the page is fetched and filtered client-side.
+    /// Pagination info is preserved, so `next_cursor()` still walks the unfiltered history.
+    ///
+    /// # Arguments
+    /// * `params` - Optional filter and pagination parameters
+    /// * `role` - Only keep fills with this role
+    pub async fn get_trades_by_role(
+        &self,
+        params: Option<GetTradesParams>,
+        role: TradeRole,
+    ) -> Result<PaginatedResponse<Trade>> {
+        let mut resp = self.get_trades(params).await?;
+        resp.data.retain(|t| t.role() == Some(role));
+        Ok(resp)
+    }
+
+    /// Get trade history across pages, up to `max_items` trades.
+    ///
+    /// Follows the pagination cursor until the history is exhausted or `max_items`
+    /// trades have been collected, so accounts with huge histories can't cause
+    /// unbounded memory use. The page size is taken from `params.limit`.
+    ///
+    /// # Arguments
+    /// * `params` - Optional filter parameters (the cursor is the starting point)
+    /// * `max_items` - Maximum number of trades to return
+    pub async fn get_all_trades(
+        &self,
+        params: Option<GetTradesParams>,
+        max_items: usize,
+    ) -> Result<Vec<Trade>> {
+        let mut params = params.unwrap_or_default();
+        let mut trades = Vec::new();
+        while trades.len() < max_items {
+            let page = self.get_trades(Some(params.clone())).await?;
+            let next = page.next_cursor();
+            let empty = page.data.is_empty();
+            trades.extend(page.data);
+            match next {
+                Some(cursor) if !empty => params.cursor = Some(cursor),
+                _ => break,
+            }
+        }
+        trades.truncate(max_items);
+        Ok(trades)
+    }
+
+    /// Get all fills for an order, following pagination until exhausted.
+    ///
+    /// # Arguments
+    /// * `order_id` - Internal order ID
+    pub async fn get_order_fills(&self, order_id: &str) -> Result<Trades> {
+        let params = GetTradesParams {
+            order_id: Some(order_id.to_string()),
+            ..Default::default()
+        };
+        let fills = self.get_all_trades(Some(params), usize::MAX).await?;
+        Ok(Trades::from(fills))
+    }
+
+    /// Compute the quantity-weighted average fill price of an order from its trades and
+    /// cross-check it against the exchange's reported `Order::average_price`.
+    ///
+    /// The order and its fills are fetched concurrently. Check `AvgFillPrice::mismatch`
+    /// to catch a reported average that disagrees with the fills; `AvgFillPrice::price`
+    /// falls back to the reported average when no fills are visible yet, and covers
+    /// orders whose reported average is null.
+    ///
+    /// # Arguments
+    /// * `order_id` - Internal order ID
+    pub async fn order_avg_fill_price(&self, order_id: &str) -> Result<AvgFillPrice> {
+        let (order, fills) = tokio::join!(self.get_order(order_id), self.get_order_fills(order_id));
+        Ok(AvgFillPrice::new(&fills?, order?.average_price))
+    }
+
     /// Get funding payment history.
     ///
     /// # Arguments
@@ -375,6 +1243,21 @@ impl PrivateApi {
             .await
     }
 
+    /// Stream funding payment history, fetching further pages as the stream is consumed.
+    ///
+    /// # Arguments
+    /// * `params` - Optional filter parameters; `limit` sets the page size
+    pub fn funding_history_stream(
+        &self,
+        params: Option<GetFundingHistoryParams>,
+    ) -> impl Stream<Item = Result<FundingPayment>> + '_ {
+        let params = params.unwrap_or_default();
+        paginate(params.cursor, move |cursor| {
+            let params = GetFundingHistoryParams { cursor, ..params.clone() };
+            self.get_funding_history(Some(params))
+        })
+    }
+
     // ========== Dead Man's Switch ==========
 
     /// Set dead man's switch countdown.
@@ -403,6 +1286,59 @@ impl PrivateApi {
         Ok(())
     }
 
+    /// Arm the dead man's switch and keep refreshing it in the background.
+    ///
+    /// Sets the countdown immediately and then every `refresh_interval` until the
+    /// returned guard is dropped. A failed refresh is retried on the next tick and
+    /// recorded on the guard, so pick an interval comfortably shorter than the countdown
+    /// (e.g. a third of it) and watch `DeadManSwitchGuard::consecutive_failures`.
+    ///
+    /// Fails with `ExtendedError::InvalidParameter` unless `refresh_interval` is shorter
+    /// than the countdown, since the switch would fire between refreshes.
+    ///
+    /// # Arguments
+    /// * `countdown_seconds` - Countdown time in seconds
+    /// * `refresh_interval` - Time between refreshes
+    pub fn spawn_dead_man_switch(
+        &self,
+        countdown_seconds: u32,
+        refresh_interval: Duration,
+    ) -> Result<DeadManSwitchGuard> {
+        if refresh_interval >= Duration::from_secs(countdown_seconds.into()) {
+            return Err(ExtendedError::InvalidParameter(format!(
+                "dead man's switch refresh interval {:?} must be shorter than the {}s countdown",
+                refresh_interval, countdown_seconds
+            )));
+        }
+
+        let api = self.clone();
+        let status = Arc::new(std::sync::Mutex::new(RefreshStatus::default()));
+        let task_status = status.clone();
+        let task = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(refresh_interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                let result = api.set_dead_man_switch(countdown_seconds).await;
+                let mut status = task_status.lock().unwrap_or_else(|e| e.into_inner());
+                match result {
+                    Ok(()) => *status = RefreshStatus::default(),
+                    Err(e) => {
+                        status.consecutive_failures += 1;
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(
+                            consecutive_failures = status.consecutive_failures,
+                            error = %e,
+                            "dead man's switch refresh failed"
+                        );
+                        status.last_error = Some(e.to_string());
+                    }
+                }
+            }
+        });
+        Ok(DeadManSwitchGuard { task, status })
+    }
+
     // ========== Withdrawal & Transfer Endpoints ==========
 
     /// Request a withdrawal.
@@ -419,6 +1355,63 @@ impl PrivateApi {
         Ok(resp.data)
     }
 
+    /// Get withdrawal history, newest first.
+    ///
+    /// # Arguments
+    /// * `params` - Pagination cursor and page size
+    pub async fn get_withdrawals(
+        &self,
+        params: PaginationParams,
+    ) -> Result<PaginatedResponse<Withdrawal>> {
+        self.client.get_with_query("user/withdrawals", &params).await
+    }
+
+    /// Get a single withdrawal by ID.
+    ///
+    /// # Arguments
+    /// * `id` - Withdrawal ID
+    pub async fn get_withdrawal(&self, id: &str) -> Result<Withdrawal> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            data: Withdrawal,
+        }
+
+        let resp: Response = self.client.get(&format!("user/withdrawals/{}", id)).await?;
+        Ok(resp.data)
+    }
+
+    /// Poll a withdrawal until it is completed or failed.
+    ///
+    /// Returns the withdrawal in its final state; a failed withdrawal is returned as
+    /// `Ok`, so check `status`. Fails with `ExtendedError::Timeout` if it is still in
+    /// flight after `timeout`.
+    ///
+    /// # Arguments
+    /// * `id` - Withdrawal ID
+    /// * `poll_interval` - Time between status checks
+    /// * `timeout` - Maximum time to wait
+    pub async fn wait_for_withdrawal(
+        &self,
+        id: &str,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Withdrawal> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let withdrawal = self.get_withdrawal(id).await?;
+            if withdrawal.status.is_final() {
+                return Ok(withdrawal);
+            }
+            if tokio::time::Instant::now() + poll_interval > deadline {
+                return Err(ExtendedError::Timeout(format!(
+                    "withdrawal {} still {:?} after {:?}",
+                    id, withdrawal.status, timeout
+                )));
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
     /// Transfer funds between sub-accounts.
     ///
     /// # Arguments
@@ -432,12 +1425,161 @@ impl PrivateApi {
         let resp: Response = self.client.post("user/transfer", &request).await?;
         Ok(resp.data)
     }
+
+    // ========== Bridge Endpoints ==========
+
+    /// Get the bridge configuration (supported chains for deposits).
+    pub async fn get_bridge_config(&self) -> Result<BridgeConfig> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            data: BridgeConfig,
+        }
+
+        let resp: Response = self.client.get("user/bridge/config").await?;
+        Ok(resp.data)
+    }
+
+    /// Get a quote for bridging funds from another chain.
+    ///
+    /// Fetches the bridge configuration first and fails with
+    /// `ExtendedError::InvalidParameter` if the source chain isn't supported or has
+    /// deposits disabled (see `BridgeConfig::check_quote_request`).
+    ///
+    /// # Arguments
+    /// * `request` - Source chain and amount
+    pub async fn get_bridge_quote(&self, request: BridgeQuoteRequest) -> Result<BridgeQuote> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            data: BridgeQuote,
+        }
+
+        self.get_bridge_config().await?.check_quote_request(&request)?;
+
+        let resp: Response = self
+            .client
+            .get_with_query("user/bridge/quote", &request)
+            .await?;
+        Ok(resp.data)
+    }
+
+    /// Initiate a cross-chain deposit by committing a bridge quote.
+    ///
+    /// The quote must not have expired (see `BridgeQuote::expires_at`).
+    ///
+    /// # Arguments
+    /// * `quote_id` - ID of a quote returned by `get_bridge_quote`
+    pub async fn initiate_bridge_deposit(&self, quote_id: &str) -> Result<()> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            #[allow(dead_code)]
+            status: String,
+        }
+
+        #[derive(serde::Serialize)]
+        struct Params<'a> {
+            id: &'a str,
+        }
+
+        let _: Response = self
+            .client
+            .post_with_query("user/bridge/quote", &Params { id: quote_id })
+            .await?;
+        Ok(())
+    }
+}
+
+/// Flatten a cursor-paginated endpoint into a stream of items.
+///
+/// `fetch` is called with the cursor of each page, starting at `start`. The stream ends
+/// after a page with no cursor or no data; an error is yielded once and ends it too.
+fn paginate<'a, T, F, Fut>(start: Option<i64>, fetch: F) -> impl Stream<Item = Result<T>> + 'a
+where
+    T: 'a,
+    F: Fn(Option<i64>) -> Fut + 'a,
+    Fut: Future<Output = Result<PaginatedResponse<T>>> + 'a,
+{
+    stream::unfold(Some(start), move |cursor| {
+        let page = cursor.map(&fetch);
+        async move {
+            match page?.await {
+                Ok(page) => {
+                    let next = page.next_cursor().filter(|_| !page.data.is_empty());
+                    let items: Vec<Result<T>> = page.data.into_iter().map(Ok).collect();
+                    Some((items, next.map(Some)))
+                }
+                Err(e) => Some((vec![Err(e)], None)),
+            }
+        }
+    })
+    .flat_map(stream::iter)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::testnet_config;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_reconciliation_report() {
+        let order = |id: &str, external_id: &str| -> Order {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "externalId": external_id,
+                "market": "BTC-USD",
+                "side": "BUY",
+                "type": "LIMIT",
+                "status": "NEW",
+                "price": "95000",
+                "qty": "0.01"
+            }))
+            .unwrap()
+        };
+        let position = |market: &str, side: &str, size: &str| -> Position {
+            serde_json::from_value(serde_json::json!({
+                "market": market,
+                "side": side,
+                "size": size,
+                "openPrice": "100",
+                "markPrice": "100",
+                "unrealisedPnl": "0",
+                "leverage": "5"
+            }))
+            .unwrap()
+        };
+
+        let open_orders = vec![order("1", "ext-1"), order("2", "ext-2"), order("3", "ext-3")];
+        let positions = vec![position("BTC-USD", "LONG", "0.5"), position("ETH-USD", "SHORT", "2")];
+        // Tracked by internal ID, external ID, and one that is gone
+        let local_orders = vec!["1".to_string(), "ext-2".to_string(), "9".to_string()];
+        let local_positions = HashMap::from([
+            ("BTC-USD".to_string(), dec!(0.5)),
+            ("ETH-USD".to_string(), dec!(-1)),
+            ("SOL-USD".to_string(), dec!(10)),
+        ]);
+
+        let report =
+            ReconciliationReport::new(open_orders, &positions, &local_orders, &local_positions);
+        assert!(!report.is_consistent());
+        let unknown: Vec<&str> = report.unknown_orders.iter().map(|o| o.id.as_str()).collect();
+        assert_eq!(unknown, ["3"]);
+        assert_eq!(report.missing_orders, ["9"]);
+        assert_eq!(
+            report.position_mismatches,
+            vec![
+                PositionMismatch {
+                    market: "ETH-USD".to_string(),
+                    local_size: dec!(-1),
+                    exchange_size: dec!(-2),
+                },
+                PositionMismatch {
+                    market: "SOL-USD".to_string(),
+                    local_size: dec!(10),
+                    exchange_size: Decimal::ZERO,
+                },
+            ]
+        );
+    }
 
     #[tokio::test]
     #[ignore] // Requires API key
@@ -1,14 +1,23 @@
 //! Public API endpoints (no authentication required).
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+
+use rust_decimal::Decimal;
 
 use crate::client::HttpClient;
-use crate::error::Result;
+use crate::error::{ExtendedError, Result};
 use crate::models::{
-    Candle, CandleType, FundingRate, GetCandlesParams, GetPublicTradesParams,
-    Market, MarketStats, OpenInterest, OrderBook, PublicTrade, TimeInterval,
+    Candle, CandleType, CollateralConfig, FundingRate, FundingScreenRow, GetCandlesParams,
+    GetPublicTradesParams, Liquidation, Market, MarketStats, OpenInterest, OrderBook,
+    PublicTrade, TimeInterval,
 };
 
+/// Asset that index prices are quoted in.
+const USD: &str = "USD";
+
+/// Candles requested per call by `get_candles_range`, the API's per-request maximum.
+const MAX_CANDLES_PER_REQUEST: u32 = 1000;
+
 /// Public API for Extended Exchange.
 ///
 /// These endpoints do not require authentication and provide market data.
@@ -51,11 +60,117 @@ impl PublicApi {
         Ok(map)
     }
 
+    /// Get the collateral asset settings used to sign transfers and withdrawals.
+    ///
+    /// Read from the markets' L2 config, which all share the same collateral.
+    ///
+    /// Fails with `ExtendedError::Api` (code "404") if the exchange lists no markets.
+    pub async fn get_collateral_config(&self) -> Result<CollateralConfig> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            data: Vec<Market>,
+        }
+        let resp: Response = self.client.get("info/markets").await?;
+        resp.data
+            .first()
+            .map(Market::collateral_config)
+            .ok_or_else(|| ExtendedError::Api {
+                code: "404".to_string(),
+                message: "no markets to read the collateral config from".to_string(),
+            })
+    }
+
+    /// Get a single market by name.
+    ///
+    /// Filters `info/markets` server-side, so only that market's payload is fetched.
+    /// Use this on the signing path when you need one market's L2 config.
+    ///
+    /// Fails with `ExtendedError::Api` (code "404") if the market doesn't exist.
+    ///
+    /// # Arguments
+    /// * `name` - Market name (e.g., "BTC-USD")
+    pub async fn get_market(&self, name: impl AsRef<str>) -> Result<Market> {
+        let name = name.as_ref();
+        #[derive(serde::Deserialize)]
+        struct Response {
+            data: Vec<Market>,
+        }
+        #[derive(serde::Serialize)]
+        struct Params<'a> {
+            market: &'a str,
+        }
+        let resp: Response = self
+            .client
+            .get_with_query("info/markets", &Params { market: name })
+            .await?;
+        resp.data
+            .into_iter()
+            .find(|m| m.name == name)
+            .ok_or_else(|| ExtendedError::Api {
+                code: "404".to_string(),
+                message: format!("market {} not found", name),
+            })
+    }
+
+    /// Get a funding-arbitrage screen across all markets.
+    ///
+    /// Returns one row per market with the current funding rate, annualized rate,
+    /// open interest and mark price, taken from the stats embedded in `get_markets()`.
+    /// Rows are sorted by absolute annualized rate (highest first); markets without
+    /// a funding rate are placed last.
+    pub async fn get_funding_screen(&self) -> Result<Vec<FundingScreenRow>> {
+        let markets = self.get_markets().await?;
+        let mut rows: Vec<FundingScreenRow> =
+            markets.values().map(FundingScreenRow::from_market).collect();
+        rows.sort_by(|a, b| {
+            let a_abs = a.annualized_rate.map(|r| r.abs());
+            let b_abs = b.annualized_rate.map(|r| r.abs());
+            b_abs.cmp(&a_abs).then_with(|| a.market.cmp(&b.market))
+        });
+        Ok(rows)
+    }
+
+    /// Get the USD index price of every asset that has a USD-quoted market.
+    ///
+    /// Prices come from the market stats embedded in `get_markets()`, so this is a
+    /// single request; USD itself is included at 1. Fetch once and keep the map when
+    /// valuing many balances instead of calling `get_asset_price` per asset.
+    pub async fn get_asset_prices(&self) -> Result<HashMap<String, Decimal>> {
+        let markets = self.get_markets().await?;
+        let mut prices: HashMap<String, Decimal> = markets
+            .into_values()
+            .filter(|m| m.collateral_asset_name == USD)
+            .map(|m| (m.asset_name, m.market_stats.index_price))
+            .collect();
+        prices.insert(USD.to_string(), Decimal::ONE);
+        Ok(prices)
+    }
+
+    /// Get the USD index price of a collateral or synthetic asset.
+    ///
+    /// Fails with `ExtendedError::InvalidParameter` if no USD-quoted market exists for
+    /// the asset.
+    ///
+    /// # Arguments
+    /// * `asset` - Asset name (e.g., "BTC", "XVS")
+    pub async fn get_asset_price(&self, asset: &str) -> Result<Decimal> {
+        if asset == USD {
+            return Ok(Decimal::ONE);
+        }
+        self.get_asset_prices()
+            .await?
+            .remove(asset)
+            .ok_or_else(|| {
+                ExtendedError::InvalidParameter(format!("no USD index price for asset {}", asset))
+            })
+    }
+
     /// Get statistics for a specific market.
     ///
     /// # Arguments
     /// * `market` - Market name (e.g., "BTC-USD")
-    pub async fn get_market_stats(&self, market: &str) -> Result<MarketStats> {
+    pub async fn get_market_stats(&self, market: impl AsRef<str>) -> Result<MarketStats> {
+        let market = market.as_ref();
         #[derive(serde::Deserialize)]
         struct Response {
             data: MarketStats,
@@ -64,12 +179,39 @@ impl PublicApi {
         Ok(resp.data)
     }
 
+    /// Get statistics for every market in one request.
+    ///
+    /// There's no bulk stats endpoint, so this reads the stats embedded in `info/markets`
+    /// and fills in each one's `market` name. Sorted by market name.
+    pub async fn get_all_market_stats(&self) -> Result<Vec<MarketStats>> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            data: Vec<Market>,
+        }
+        let resp: Response = self.client.get("info/markets").await?;
+        let mut stats: Vec<MarketStats> = resp
+            .data
+            .into_iter()
+            .map(|m| MarketStats {
+                market: Some(m.name),
+                ..m.market_stats
+            })
+            .collect();
+        stats.sort_by(|a, b| a.market.cmp(&b.market));
+        Ok(stats)
+    }
+
     /// Get order book for a market.
     ///
     /// # Arguments
     /// * `market` - Market name (e.g., "BTC-USD")
     /// * `depth` - Optional depth limit (default is full book)
-    pub async fn get_orderbook(&self, market: &str, depth: Option<u32>) -> Result<OrderBook> {
+    pub async fn get_orderbook(
+        &self,
+        market: impl AsRef<str>,
+        depth: Option<u32>,
+    ) -> Result<OrderBook> {
+        let market = market.as_ref();
         #[derive(serde::Deserialize)]
         struct Response {
             data: OrderBook,
@@ -92,9 +234,10 @@ impl PublicApi {
     /// * `params` - Optional parameters (limit)
     pub async fn get_trades(
         &self,
-        market: &str,
+        market: impl AsRef<str>,
         params: Option<GetPublicTradesParams>,
     ) -> Result<Vec<PublicTrade>> {
+        let market = market.as_ref();
         #[derive(serde::Deserialize)]
         struct Response {
             data: Vec<PublicTrade>,
@@ -109,6 +252,36 @@ impl PublicApi {
         Ok(resp.data)
     }
 
+    /// Get recent liquidations in a market, newest first.
+    ///
+    /// # Arguments
+    /// * `market` - Market name (e.g., "BTC-USD")
+    /// * `limit` - Optional limit on number of results
+    pub async fn get_liquidations(
+        &self,
+        market: impl AsRef<str>,
+        limit: Option<u32>,
+    ) -> Result<Vec<Liquidation>> {
+        let market = market.as_ref();
+        #[derive(serde::Deserialize)]
+        struct Response {
+            data: Vec<Liquidation>,
+        }
+
+        #[derive(serde::Serialize)]
+        struct Params {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            limit: Option<u32>,
+        }
+
+        let path = format!("info/markets/{}/liquidations", market);
+        let resp: Response = self
+            .client
+            .get_with_query(&path, &Params { limit })
+            .await?;
+        Ok(resp.data)
+    }
+
     /// Get candlestick data for a market.
     ///
     /// # Arguments
@@ -135,10 +308,11 @@ impl PublicApi {
     /// ```
     pub async fn get_candles(
         &self,
-        market: &str,
+        market: impl AsRef<str>,
         candle_type: CandleType,
         params: GetCandlesParams,
     ) -> Result<Vec<Candle>> {
+        let market = market.as_ref();
         #[derive(serde::Deserialize)]
         struct Response {
             data: Vec<Candle>,
@@ -155,6 +329,58 @@ impl PublicApi {
         Ok(resp.data)
     }
 
+    /// Get every candle between `start` and `end` (Unix ms, inclusive), however many.
+    ///
+    /// Splits the range into windows of at most the API's per-request maximum, fetches
+    /// them in order and returns one series sorted by time, with the candles on window
+    /// boundaries deduplicated. Periods without candles (e.g. market downtime) are left
+    /// as gaps rather than filled in.
+    ///
+    /// Fails with `ExtendedError::InvalidParameter` if `start` is after `end`.
+    ///
+    /// # Arguments
+    /// * `market` - Market name (e.g., "BTC-USD")
+    /// * `candle_type` - Type of candle (trades, mark, or index)
+    /// * `interval` - Candle interval
+    /// * `start` - Start of the range (Unix ms)
+    /// * `end` - End of the range (Unix ms)
+    pub async fn get_candles_range(
+        &self,
+        market: impl AsRef<str>,
+        candle_type: CandleType,
+        interval: TimeInterval,
+        start: i64,
+        end: i64,
+    ) -> Result<Vec<Candle>> {
+        let market = market.as_ref();
+        if start > end {
+            return Err(ExtendedError::InvalidParameter(format!(
+                "candle range start {} is after end {}",
+                start, end
+            )));
+        }
+
+        let window = interval.millis() * i64::from(MAX_CANDLES_PER_REQUEST - 1);
+        let mut candles = BTreeMap::new();
+        let mut window_start = start;
+        loop {
+            let window_end = window_start.saturating_add(window).min(end);
+            let params = GetCandlesParams::new(interval)
+                .with_range(window_start, window_end)
+                .with_limit(MAX_CANDLES_PER_REQUEST);
+            for candle in self.get_candles(market, candle_type, params).await? {
+                if (start..=end).contains(&candle.timestamp) {
+                    candles.insert(candle.timestamp, candle);
+                }
+            }
+            if window_end >= end {
+                break;
+            }
+            window_start = window_end;
+        }
+        Ok(candles.into_values().collect())
+    }
+
     /// Get funding rate history for a market.
     ///
     /// # Arguments
@@ -162,9 +388,10 @@ impl PublicApi {
     /// * `limit` - Optional limit on number of results
     pub async fn get_funding_rates(
         &self,
-        market: &str,
+        market: impl AsRef<str>,
         limit: Option<u32>,
     ) -> Result<Vec<FundingRate>> {
+        let market = market.as_ref();
         #[derive(serde::Deserialize)]
         struct Response {
             data: Vec<FundingRate>,
@@ -192,10 +419,11 @@ impl PublicApi {
     /// * `limit` - Optional limit on number of results
     pub async fn get_open_interest(
         &self,
-        market: &str,
+        market: impl AsRef<str>,
         interval: TimeInterval,
         limit: Option<u32>,
     ) -> Result<Vec<OpenInterest>> {
+        let market = market.as_ref();
         #[derive(serde::Deserialize)]
         struct Response {
             data: Vec<OpenInterest>,
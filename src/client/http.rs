@@ -1,23 +1,67 @@
 //! HTTP client for Extended Exchange REST API.
 
-use reqwest::{header, Client, Method, Response};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use reqwest::{header, Client, Method, Proxy};
 use serde::{de::DeserializeOwned, Serialize};
 use url::Url;
 
+use super::rate_limit::RateLimiter;
+use super::{HttpTransport, RateLimitInfo, ReqwestTransport, TransportRequest, TransportResponse};
 use crate::config::EndpointConfig;
 use crate::error::{ApiErrorResponse, ExtendedError, Result};
 
 /// HTTP client for making requests to the Extended Exchange API.
-#[derive(Debug, Clone)]
+///
+/// Requests go through the config's `transport` if set, else through `reqwest` with the
+/// config's timeouts and proxy. With a `rate_limit` in the config, order requests wait
+/// for a slot in a token bucket shared by clones of the client. `Debug` output redacts
+/// the API key.
+#[derive(Clone)]
 pub struct HttpClient {
-    client: Client,
+    transport: Arc<dyn HttpTransport>,
     config: EndpointConfig,
     api_key: Option<String>,
+    last_rate_limit: Arc<Mutex<Option<RateLimitInfo>>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+impl fmt::Debug for HttpClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpClient")
+            .field("transport", &self.transport)
+            .field("config", &self.config)
+            .field("api_key", &self.api_key.as_ref().map(|_| "***redacted***"))
+            .field("last_rate_limit", &self.last_rate_limit)
+            .field("rate_limiter", &self.rate_limiter)
+            .finish()
+    }
 }
 
 impl HttpClient {
     /// Create a new HTTP client with the given configuration.
+    ///
+    /// Fails with `ExtendedError::Config` if the configuration doesn't validate.
     pub fn new(config: EndpointConfig) -> Result<Self> {
+        config.validate()?;
+
+        let transport = match config.transport {
+            Some(ref transport) => transport.clone(),
+            None => Arc::new(ReqwestTransport::new(Self::build_client(&config)?)),
+        };
+        let rate_limiter = config.rate_limit.map(|limit| Arc::new(RateLimiter::new(limit)));
+        Ok(Self {
+            transport,
+            config,
+            api_key: None,
+            last_rate_limit: Arc::new(Mutex::new(None)),
+            rate_limiter,
+        })
+    }
+
+    /// Build the `reqwest` client for the default transport.
+    fn build_client(config: &EndpointConfig) -> Result<Client> {
         let mut headers = header::HeaderMap::new();
         headers.insert(
             header::USER_AGENT,
@@ -32,15 +76,16 @@ impl HttpClient {
             header::HeaderValue::from_static("application/json"),
         );
 
-        let client = Client::builder()
+        let mut builder = Client::builder()
             .default_headers(headers)
-            .build()?;
-
-        Ok(Self {
-            client,
-            config,
-            api_key: None,
-        })
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout);
+        if let Some(ref proxy) = config.proxy {
+            builder = builder.proxy(Proxy::all(proxy.as_str())?);
+        } else if !config.system_proxy {
+            builder = builder.no_proxy();
+        }
+        Ok(builder.build()?)
     }
 
     /// Create a new HTTP client with API key authentication.
@@ -55,6 +100,23 @@ impl HttpClient {
         &self.config
     }
 
+    /// Get a copy of this client that targets a different API version (e.g., "api/v2").
+    ///
+    /// The copy shares the connection pool, API key and rate-limit tracking, so it can
+    /// be used for individual calls during a gradual migration.
+    pub fn with_api_version(&self, api_version: impl Into<String>) -> Self {
+        let mut client = self.clone();
+        client.config = client.config.with_api_version(api_version);
+        client
+    }
+
+    /// Get the most recent rate-limit headers seen on any response.
+    ///
+    /// Shared between clones of this client. Use it to throttle before hitting 429s.
+    pub fn last_rate_limit(&self) -> Option<RateLimitInfo> {
+        *self.last_rate_limit.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
     /// Make a GET request.
     pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
         self.request(Method::GET, path, Option::<&()>::None).await
@@ -68,15 +130,7 @@ impl HttpClient {
     ) -> Result<T> {
         let base_url = self.config.api_url(path);
         let url = self.build_url_with_query(&base_url, query)?;
-
-        let mut request = self.client.get(url);
-
-        if let Some(ref api_key) = self.api_key {
-            request = request.header("X-Api-Key", api_key);
-        }
-
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(Method::GET, url, Option::<&()>::None).await
     }
 
     /// Make a POST request.
@@ -93,6 +147,27 @@ impl HttpClient {
         self.request(Method::POST, path, Option::<&()>::None).await
     }
 
+    /// Make a POST request with query parameters and no body.
+    pub async fn post_with_query<T: DeserializeOwned, Q: Serialize>(
+        &self,
+        path: &str,
+        query: &Q,
+    ) -> Result<T> {
+        let base_url = self.config.api_url(path);
+        let url = self.build_url_with_query(&base_url, query)?;
+        self.send(Method::POST, url, Option::<&()>::None).await
+    }
+
+    /// Make a POST request to a path outside the versioned API (e.g., `auth/onboard`).
+    pub async fn post_unversioned<T: DeserializeOwned, B: Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T> {
+        let url = Url::parse(&self.config.base_url(path))?;
+        self.send(Method::POST, url, Some(body)).await
+    }
+
     /// Make a PATCH request.
     pub async fn patch<T: DeserializeOwned, B: Serialize>(
         &self,
@@ -115,15 +190,7 @@ impl HttpClient {
     ) -> Result<T> {
         let base_url = self.config.api_url(path);
         let url = self.build_url_with_query(&base_url, query)?;
-
-        let mut request = self.client.delete(url);
-
-        if let Some(ref api_key) = self.api_key {
-            request = request.header("X-Api-Key", api_key);
-        }
-
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send(Method::DELETE, url, Option::<&()>::None).await
     }
 
     /// Build a URL with query parameters.
@@ -150,28 +217,125 @@ impl HttpClient {
         path: &str,
         body: Option<&B>,
     ) -> Result<T> {
-        let url = self.config.api_url(path);
-        let mut request = self.client.request(method, &url);
+        let url = Url::parse(&self.config.api_url(path))?;
+        self.send(method, url, body).await
+    }
 
+    /// Internal method to send a request to a full URL with optional body.
+    ///
+    /// With the `tracing` feature, the request runs in an `http_request` span.
+    async fn send<T: DeserializeOwned, B: Serialize>(
+        &self,
+        method: Method,
+        url: Url,
+        body: Option<&B>,
+    ) -> Result<T> {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+            // Only the path is recorded: the API key travels in a header, which is never
+            // logged, and query strings are left out too
+            let span = tracing::debug_span!("http_request", method = %method, path = url.path());
+            self.send_with_retry(method, url, body).instrument(span).await
+        }
+        #[cfg(not(feature = "tracing"))]
+        self.send_with_retry(method, url, body).await
+    }
+
+    /// Send a request, retrying transient failures according to the configured
+    /// `RetryConfig`.
+    ///
+    /// Every attempt of a non-GET request waits for the rate limiter, if there is one.
+    async fn send_with_retry<T: DeserializeOwned, B: Serialize>(
+        &self,
+        method: Method,
+        url: Url,
+        body: Option<&B>,
+    ) -> Result<T> {
+        let retry = &self.config.retry;
+        let body = body.map(serde_json::to_string).transpose()?;
+        let mut headers = header::HeaderMap::new();
         if let Some(ref api_key) = self.api_key {
-            request = request.header("X-Api-Key", api_key);
+            let mut value = header::HeaderValue::from_str(api_key).map_err(|_| {
+                ExtendedError::Config("API key is not a valid header value".to_string())
+            })?;
+            value.set_sensitive(true);
+            headers.insert("X-Api-Key", value);
         }
+        let mut attempt = 1;
+
+        loop {
+            let request = TransportRequest {
+                method: method.clone(),
+                url: url.clone(),
+                headers: headers.clone(),
+                body: body.clone(),
+            };
+
+            if method != Method::GET {
+                if let Some(ref limiter) = self.rate_limiter {
+                    limiter.acquire().await;
+                }
+            }
 
-        if let Some(body) = body {
-            request = request.json(body);
+            #[cfg(feature = "tracing")]
+            let started = std::time::Instant::now();
+            let result = self.transport.send(request).await;
+            #[cfg(feature = "tracing")]
+            {
+                let latency_ms = started.elapsed().as_millis() as u64;
+                match &result {
+                    Ok(response) => {
+                        let status = response.status;
+                        tracing::debug!(attempt, status, latency_ms, "response received");
+                    }
+                    Err(e) => tracing::debug!(attempt, error = %e, latency_ms, "request failed"),
+                }
+            }
+            if attempt < retry.max_attempts && Self::is_retryable(&method, &result) {
+                let retry_after = result
+                    .as_ref()
+                    .ok()
+                    .and_then(|r| RateLimitInfo::from_headers(&r.headers))
+                    .and_then(|info| info.retry_after)
+                    .unwrap_or_default();
+                tokio::time::sleep(retry.backoff(attempt).max(retry_after)).await;
+                attempt += 1;
+                continue;
+            }
+
+            return self.handle_response(result?);
         }
+    }
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+    /// Check whether a failed attempt is safe and worthwhile to retry.
+    ///
+    /// Only GETs are idempotent here; other methods are retried only when the
+    /// connection was never established. Transport errors are retried only if they're
+    /// transient (connect failures, timeouts, and failures reading the response
+    /// body), not e.g. a request that couldn't be built.
+    fn is_retryable(method: &Method, result: &Result<TransportResponse>) -> bool {
+        let idempotent = *method == Method::GET;
+        match result {
+            Ok(response) => idempotent && (response.status == 429 || response.status >= 500),
+            Err(ExtendedError::Connection(_)) => true,
+            Err(ExtendedError::RequestTimeout(_)) => idempotent,
+            Err(ExtendedError::Http(e)) => idempotent && e.is_body(),
+            Err(_) => false,
+        }
     }
 
     /// Handle the API response, checking for errors.
-    async fn handle_response<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
-        let status = response.status();
+    fn handle_response<T: DeserializeOwned>(&self, response: TransportResponse) -> Result<T> {
+        let status = response.status;
+        let rate_limit = RateLimitInfo::from_headers(&response.headers);
+        if rate_limit.is_some() {
+            *self.last_rate_limit.lock().unwrap_or_else(|e| e.into_inner()) = rate_limit;
+        }
 
-        if status.is_success() {
-            // Get text first for better error messages
-            let text = response.text().await?;
+        if (200..300).contains(&status) {
+            // Parse from text for better error messages
+            let text = response.body;
             match serde_json::from_str::<T>(&text) {
                 Ok(body) => Ok(body),
                 Err(e) => {
@@ -189,15 +353,20 @@ impl HttpClient {
                     )))
                 }
             }
-        } else if status.as_u16() == 429 {
-            Err(ExtendedError::RateLimitExceeded)
+        } else if status == 429 {
+            Err(ExtendedError::RateLimitExceeded {
+                retry_after: rate_limit.and_then(|info| info.retry_after),
+                remaining: rate_limit.and_then(|info| info.remaining),
+            })
         } else {
             // Try to parse as API error response
-            let text = response.text().await?;
+            let text = response.body;
+            #[cfg(feature = "tracing")]
+            tracing::warn!(status, body = %text, "API error response");
             match serde_json::from_str::<ApiErrorResponse>(&text) {
                 Ok(error_resp) => Err(ExtendedError::from(error_resp)),
                 Err(_) => Err(ExtendedError::Api {
-                    code: status.as_u16().to_string(),
+                    code: status.to_string(),
                     message: if text.is_empty() {
                         "(no response body)".to_string()
                     } else {
@@ -209,4 +378,80 @@ impl HttpClient {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::testnet_config;
+    use std::time::Duration;
 
+    fn fake_response(status: u16, headers: &[(&'static str, &str)]) -> TransportResponse {
+        let mut header_map = header::HeaderMap::new();
+        for (name, value) in headers {
+            header_map.insert(*name, value.parse().unwrap());
+        }
+        TransportResponse {
+            status,
+            headers: header_map,
+            body: r#"{"status":"OK"}"#.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_debug_redacts_api_key() {
+        let client = HttpClient::with_api_key(testnet_config(), "a1b2c3d4e5f6a7b8").unwrap();
+        let debug = format!("{:?}", client);
+        assert!(debug.contains(r#"api_key: Some("***redacted***")"#));
+        assert!(!debug.contains("a1b2c3d4e5f6a7b8"));
+
+        let debug = format!("{:?}", HttpClient::new(testnet_config()).unwrap());
+        assert!(debug.contains("api_key: None"));
+    }
+
+    #[test]
+    fn test_rate_limit_headers_on_429() {
+        let client = HttpClient::new(testnet_config()).unwrap();
+        let response = fake_response(
+            429,
+            &[("X-RateLimit-Remaining", "0"), ("X-RateLimit-Reset", "12")],
+        );
+
+        let err = client.handle_response::<serde_json::Value>(response).unwrap_err();
+        match err {
+            ExtendedError::RateLimitExceeded { retry_after, remaining } => {
+                assert_eq!(retry_after, Some(Duration::from_secs(12)));
+                assert_eq!(remaining, Some(0));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_last_rate_limit_tracks_successful_responses() {
+        let client = HttpClient::new(testnet_config()).unwrap();
+        assert_eq!(client.last_rate_limit(), None);
+
+        let response = fake_response(
+            200,
+            &[("X-RateLimit-Limit", "1000"), ("X-RateLimit-Remaining", "998")],
+        );
+        let _: serde_json::Value = client.handle_response(response).unwrap();
+
+        let info = client.clone().last_rate_limit().unwrap();
+        assert_eq!(info.limit, Some(1000));
+        assert_eq!(info.remaining, Some(998));
+        assert_eq!(info.retry_after, None);
+    }
+
+    #[tokio::test]
+    async fn test_only_transient_errors_are_retried() {
+        let invalid = reqwest::Client::new().get("not a url").send().await.unwrap_err();
+        assert!(!HttpClient::is_retryable(&Method::GET, &Err(invalid.into())));
+
+        // Connecting to port 0 is always refused
+        let refused = reqwest::get("http://127.0.0.1:0").await.unwrap_err();
+        assert!(HttpClient::is_retryable(&Method::POST, &Err(refused.into())));
+
+        assert!(HttpClient::is_retryable(&Method::GET, &Ok(fake_response(503, &[]))));
+        assert!(!HttpClient::is_retryable(&Method::POST, &Ok(fake_response(503, &[]))));
+    }
+}
@@ -1,5 +1,11 @@
 //! HTTP client module for Extended Exchange API.
 
 mod http;
+mod rate_limit;
+mod transport;
 
 pub use http::HttpClient;
+pub use rate_limit::RateLimitInfo;
+pub use transport::{
+    HttpTransport, MockTransport, ReqwestTransport, TransportRequest, TransportResponse,
+};
@@ -0,0 +1,111 @@
+//! Rate-limit header parsing and client-side request pacing.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::HeaderMap;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+use crate::config::RateLimitConfig;
+
+/// Header carrying the request quota for the current window.
+const LIMIT_HEADER: &str = "X-RateLimit-Limit";
+/// Header carrying the requests remaining in the current window.
+const REMAINING_HEADER: &str = "X-RateLimit-Remaining";
+/// Header carrying when the current window resets.
+const RESET_HEADER: &str = "X-RateLimit-Reset";
+
+/// Values above this are treated as Unix timestamps rather than second counts.
+const EPOCH_THRESHOLD_SECS: u64 = 1_000_000_000;
+
+/// Rate-limit state reported by the API in response headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RateLimitInfo {
+    /// Request quota for the current window.
+    pub limit: Option<u32>,
+    /// Requests remaining in the current window.
+    pub remaining: Option<u32>,
+    /// Time until the window resets (or until a retry is allowed).
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimitInfo {
+    /// Parse rate-limit headers, returning `None` if none are present.
+    ///
+    /// `Retry-After` takes precedence over `X-RateLimit-Reset` for `retry_after`. Reset
+    /// values are accepted either as seconds from now or as a Unix timestamp.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let number = |name: &str| -> Option<u64> {
+            headers.get(name)?.to_str().ok()?.trim().parse::<f64>().ok().map(|v| v as u64)
+        };
+
+        let retry_after = number(reqwest::header::RETRY_AFTER.as_str())
+            .or_else(|| number(RESET_HEADER))
+            .map(|secs| {
+                if secs > EPOCH_THRESHOLD_SECS {
+                    let now = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_secs();
+                    Duration::from_secs(secs.saturating_sub(now))
+                } else {
+                    Duration::from_secs(secs)
+                }
+            });
+
+        let info = Self {
+            limit: number(LIMIT_HEADER).map(|v| v as u32),
+            remaining: number(REMAINING_HEADER).map(|v| v as u32),
+            retry_after,
+        };
+        (info != Self::default()).then_some(info)
+    }
+}
+
+/// Token bucket pacing requests to a `RateLimitConfig`.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    config: RateLimitConfig,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// Create a limiter with a full bucket.
+    pub(crate) fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            bucket: Mutex::new(Bucket {
+                tokens: config.burst as f64,
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Wait until a request may be sent, then take its token.
+    ///
+    /// A waiter holds the lock while it sleeps, so waiting requests go out in the order
+    /// they arrived.
+    pub(crate) async fn acquire(&self) {
+        let rate = self.config.requests_per_second as f64;
+        let mut bucket = self.bucket.lock().await;
+        let now = Instant::now();
+        let refill = now.duration_since(bucket.refilled_at).as_secs_f64() * rate;
+        bucket.tokens = (bucket.tokens + refill).min(self.config.burst as f64);
+        bucket.refilled_at = now;
+
+        if bucket.tokens < 1.0 {
+            let wait = Duration::from_secs_f64((1.0 - bucket.tokens) / rate);
+            tokio::time::sleep(wait).await;
+            // Count from when the token became due, not from when this task woke up
+            bucket.tokens = 1.0;
+            bucket.refilled_at = now + wait;
+        }
+        bucket.tokens -= 1.0;
+    }
+}
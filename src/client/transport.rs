@@ -0,0 +1,168 @@
+//! Pluggable HTTP transport under `HttpClient`.
+
+use std::fmt;
+use std::sync::Mutex;
+
+use futures::future::BoxFuture;
+use reqwest::header::HeaderMap;
+use reqwest::{Client, Method};
+use url::Url;
+
+use crate::error::Result;
+
+/// A request as handed to a transport, with the body already serialized to JSON.
+#[derive(Debug, Clone)]
+pub struct TransportRequest {
+    /// HTTP method.
+    pub method: Method,
+    /// Full URL, including the query string.
+    pub url: Url,
+    /// Per-request headers (the API key, marked sensitive so `Debug` hides it).
+    pub headers: HeaderMap,
+    /// JSON body, if any.
+    pub body: Option<String>,
+}
+
+/// A raw response from a transport, before status checks or deserialization.
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    /// HTTP status code.
+    pub status: u16,
+    /// Response headers.
+    pub headers: HeaderMap,
+    /// Response body.
+    pub body: String,
+}
+
+/// Sends HTTP requests for an `HttpClient`.
+///
+/// `HttpClient` handles everything above the wire (serialization, retries, rate-limit
+/// headers, error mapping), so a transport only moves bytes. The default sends requests
+/// with `reqwest`; plug in another with `EndpointConfig::with_transport`, e.g. a
+/// [`MockTransport`] to unit-test code using the clients without a live exchange.
+pub trait HttpTransport: Send + Sync + fmt::Debug {
+    /// Send a request and return the response, whatever its status.
+    ///
+    /// Only fail when no response was received (e.g. the connection failed).
+    fn send(&self, request: TransportRequest) -> BoxFuture<'_, Result<TransportResponse>>;
+}
+
+/// Transport sending requests with a `reqwest` client.
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    /// Create a transport over a configured `reqwest` client.
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn send(&self, request: TransportRequest) -> BoxFuture<'_, Result<TransportResponse>> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(request.method, request.url)
+                .headers(request.headers);
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+            let response = builder.send().await?;
+            Ok(TransportResponse {
+                status: response.status().as_u16(),
+                headers: response.headers().clone(),
+                body: response.text().await?,
+            })
+        })
+    }
+}
+
+/// Transport returning canned JSON responses by path, for tests.
+///
+/// Paths match the end of the request path, so `"user/balance"` matches
+/// `/api/v1/user/balance` whatever the method or query string. The first matching
+/// response wins; requests matching none get a 404. Requests are recorded for
+/// inspection with [`requests`](Self::requests).
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+///
+/// use extended_rust_sdk::api::PrivateApi;
+/// use extended_rust_sdk::client::{HttpClient, MockTransport};
+/// use extended_rust_sdk::config::testnet_config;
+/// use rust_decimal::Decimal;
+/// use serde_json::json;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> extended_rust_sdk::error::Result<()> {
+/// let transport = Arc::new(MockTransport::new().with_json(
+///     "user/balance",
+///     json!({ "status": "OK", "data": { "balance": "1000", "equity": "1050" } }),
+/// ));
+/// let config = testnet_config().with_transport(transport.clone());
+/// let api = PrivateApi::new(HttpClient::with_api_key(config, "test-key")?);
+///
+/// let balance = api.get_balance().await?;
+/// assert_eq!(balance.equity, Decimal::from(1050));
+/// assert_eq!(transport.requests().len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    responses: Vec<(String, u16, String)>,
+    requests: Mutex<Vec<TransportRequest>>,
+}
+
+impl MockTransport {
+    /// Create a transport with no canned responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Respond to requests for `path` with 200 and `body`.
+    pub fn with_json(self, path: impl Into<String>, body: serde_json::Value) -> Self {
+        self.with_response(path, 200, body)
+    }
+
+    /// Respond to requests for `path` with `status` and `body`.
+    pub fn with_response(
+        mut self,
+        path: impl Into<String>,
+        status: u16,
+        body: serde_json::Value,
+    ) -> Self {
+        let path = format!("/{}", path.into().trim_start_matches('/'));
+        self.responses.push((path, status, body.to_string()));
+        self
+    }
+
+    /// Get the requests sent so far, oldest first.
+    pub fn requests(&self) -> Vec<TransportRequest> {
+        self.requests.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+impl HttpTransport for MockTransport {
+    fn send(&self, request: TransportRequest) -> BoxFuture<'_, Result<TransportResponse>> {
+        let path = request.url.path();
+        let response = match self.responses.iter().find(|(p, ..)| path.ends_with(p.as_str())) {
+            Some((_, status, body)) => TransportResponse {
+                status: *status,
+                headers: HeaderMap::new(),
+                body: body.clone(),
+            },
+            None => TransportResponse {
+                status: 404,
+                headers: HeaderMap::new(),
+                body: format!("no mock response for {} {}", request.method, path),
+            },
+        };
+        self.requests.lock().unwrap_or_else(|e| e.into_inner()).push(request);
+        Box::pin(async move { Ok(response) })
+    }
+}
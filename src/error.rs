@@ -1,5 +1,7 @@
 //! Error types for the Extended SDK.
 
+use std::time::Duration;
+
 use thiserror::Error;
 
 /// Result type alias for Extended SDK operations.
@@ -8,9 +10,15 @@ pub type Result<T> = std::result::Result<T, ExtendedError>;
 /// Main error type for the Extended SDK.
 #[derive(Error, Debug)]
 pub enum ExtendedError {
-    /// HTTP transport error.
+    /// HTTP transport error other than a timeout or connection failure.
     #[error("HTTP error: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(#[source] reqwest::Error),
+
+    /// Could not connect to the server (refused, DNS failure, TLS handshake, proxy).
+    ///
+    /// The request never reached the server, so it's safe to retry any request.
+    #[error("Connection error: {0}")]
+    Connection(#[source] reqwest::Error),
 
     /// API returned an error response.
     #[error("API error {code}: {message}")]
@@ -21,6 +29,14 @@ pub enum ExtendedError {
         message: String,
     },
 
+    /// Invalid endpoint configuration.
+    #[error("Configuration error: {0}")]
+    Config(String),
+
+    /// WebSocket stream error (connection failure, protocol error).
+    #[error("WebSocket error: {0}")]
+    WebSocket(String),
+
     /// JSON serialization/deserialization error.
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
@@ -41,13 +57,78 @@ pub enum ExtendedError {
     #[error("Authentication error: {0}")]
     Authentication(String),
 
+    /// HTTP request exceeded `EndpointConfig::timeout`.
+    ///
+    /// The request may still have been acted on by the server.
+    #[error("HTTP request timed out: {0}")]
+    RequestTimeout(#[source] reqwest::Error),
+
+    /// Operation did not finish within the allotted time (e.g. a polling helper giving up).
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
     /// Rate limit exceeded.
-    #[error("Rate limit exceeded")]
-    RateLimitExceeded,
+    #[error(
+        "Rate limit exceeded{}",
+        retry_after.map(|d| format!(" (retry after {:?})", d)).unwrap_or_default()
+    )]
+    RateLimitExceeded {
+        /// How long to wait before retrying, if the API said.
+        retry_after: Option<Duration>,
+        /// Requests remaining in the current window, if the API said.
+        remaining: Option<u32>,
+    },
 
     /// Order validation error.
     #[error("Order validation error: {0}")]
     OrderValidation(String),
+
+    /// Order rejected for a specific reason the caller may want to react to.
+    #[error("Order rejected ({reason:?}): {message}")]
+    OrderRejected {
+        /// Why the order was rejected.
+        reason: OrderRejectReason,
+        /// Error message from the API.
+        message: String,
+    },
+
+    /// Order book update arrived out of sequence; the book must be resynced.
+    #[error("Order book sequence gap: expected {expected}, received {received}")]
+    SequenceGap {
+        /// Next sequence number that was expected.
+        expected: i64,
+        /// Sequence number that was received.
+        received: i64,
+    },
+}
+
+/// Order rejection reasons that get their own error, split out of the 1120-1148
+/// validation range so callers can handle them without parsing messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OrderRejectReason {
+    /// A post-only order would have crossed the book and taken liquidity.
+    ///
+    /// Nothing was placed; a market maker can re-quote one tick further from the touch.
+    PostOnlyCrossed,
+}
+
+impl OrderRejectReason {
+    /// API error code for a post-only order that would cross the book.
+    pub const POST_ONLY_CROSSED_CODE: i32 = 1140;
+
+    /// Map an API error code and message to a rejection reason, if it's one of these.
+    ///
+    /// The message is checked too, so the mapping survives code renumbering.
+    pub fn from_api_error(code: i32, message: &str) -> Option<Self> {
+        let message = message.to_ascii_lowercase();
+        let post_only = message.contains("post-only") || message.contains("post only");
+        if code == Self::POST_ONLY_CROSSED_CODE || post_only {
+            Some(Self::PostOnlyCrossed)
+        } else {
+            None
+        }
+    }
 }
 
 /// API error response structure from Extended Exchange.
@@ -81,6 +162,20 @@ pub struct ApiErrorDetail {
     pub message: String,
 }
 
+/// Connection failures map to `Connection` and timeouts to `RequestTimeout`; a timeout
+/// while connecting counts as a connection failure, since nothing was sent.
+impl From<reqwest::Error> for ExtendedError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_connect() {
+            ExtendedError::Connection(e)
+        } else if e.is_timeout() {
+            ExtendedError::RequestTimeout(e)
+        } else {
+            ExtendedError::Http(e)
+        }
+    }
+}
+
 impl From<ApiErrorResponse> for ExtendedError {
     fn from(resp: ApiErrorResponse) -> Self {
         ExtendedError::from_api_error(resp.error.code, resp.error.message)
@@ -94,15 +189,71 @@ impl ExtendedError {
         match &code {
             ErrorCode::Numeric(n) => match n {
                 // Rate limit errors
-                429 => ExtendedError::RateLimitExceeded,
+                429 => ExtendedError::RateLimitExceeded {
+                    retry_after: None,
+                    remaining: None,
+                },
                 // Authentication errors (1100-1102)
                 1100..=1102 => ExtendedError::Authentication(message),
                 // Order validation errors (1120-1148)
-                1120..=1148 => ExtendedError::OrderValidation(message),
+                1120..=1148 => match OrderRejectReason::from_api_error(*n, &message) {
+                    Some(reason) => ExtendedError::OrderRejected { reason, message },
+                    None => ExtendedError::OrderValidation(message),
+                },
                 // Generic API error
                 _ => ExtendedError::Api { code: code.to_string(), message },
             },
             ErrorCode::Text(_) => ExtendedError::Api { code: code.to_string(), message },
         }
     }
+
+    /// Check whether the API reported that the requested resource doesn't exist.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, ExtendedError::Api { code, .. } if code == "404" || code == "NOT_FOUND")
+    }
+
+    /// Get the rejection reason if this is an `OrderRejected` error.
+    pub fn reject_reason(&self) -> Option<OrderRejectReason> {
+        match self {
+            ExtendedError::OrderRejected { reason, .. } => Some(*reason),
+            _ => None,
+        }
+    }
+
+    /// Check whether a post-only order was rejected because it would have crossed.
+    pub fn is_post_only_crossed(&self) -> bool {
+        self.reject_reason() == Some(OrderRejectReason::PostOnlyCrossed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_reject_reason_mapping() {
+        let err = |code: i32, message: &str| {
+            ExtendedError::from_api_error(ErrorCode::Numeric(code), message.to_string())
+        };
+
+        let crossed = err(OrderRejectReason::POST_ONLY_CROSSED_CODE, "Order would cross");
+        assert!(crossed.is_post_only_crossed());
+        assert!(matches!(
+            crossed,
+            ExtendedError::OrderRejected { reason: OrderRejectReason::PostOnlyCrossed, .. }
+        ));
+        assert!(err(1137, "Post-only order would be filled immediately").is_post_only_crossed());
+
+        let invalid = err(1125, "Invalid price precision");
+        assert!(matches!(invalid, ExtendedError::OrderValidation(_)));
+        assert_eq!(invalid.reject_reason(), None);
+        assert!(!err(1100, "Post only").is_post_only_crossed());
+    }
+
+    #[tokio::test]
+    async fn test_request_errors_map_to_http() {
+        let invalid = reqwest::Client::new().get("not a url").send().await.unwrap_err();
+        let err = ExtendedError::from(invalid);
+        assert!(matches!(err, ExtendedError::Http(ref e) if e.is_builder()), "{:?}", err);
+    }
 }
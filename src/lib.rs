@@ -130,6 +130,13 @@
 //! # Ok::<(), extended_rust_sdk::error::ExtendedError>(())
 //! ```
 //!
+//! ## Features
+//!
+//! - `tracing` - Emit [`tracing`](https://docs.rs/tracing) spans and events for each
+//!   HTTP request: an `http_request` span with the method and path, the status and
+//!   latency of each attempt at debug level, and API error bodies at warn level.
+//!   The API key is never logged.
+//!
 //! ## Dependency Version Policy
 //!
 //! This SDK uses recent/current versions of all dependencies to ensure compatibility
@@ -148,18 +155,24 @@ pub mod client;
 pub mod config;
 pub mod error;
 pub mod models;
+pub mod paper;
 pub mod signing;
+pub mod stream;
 mod trading_client;
 
 // Re-export main types at crate root
-pub use trading_client::{PublicOnlyClient, ReadOnlyClient, TradingClient, TradingClientBuilder};
+pub use trading_client::{
+    PublicOnlyClient, ReadOnlyClient, ReduceOnlyCheck, TradingClient, TradingClientBuilder,
+};
 
 /// Prelude module for convenient imports.
 pub mod prelude {
     pub use crate::api::{PrivateApi, PublicApi};
     pub use crate::config::{mainnet_config, testnet_config, EndpointConfig};
-    pub use crate::error::{ExtendedError, Result};
+    pub use crate::error::{ExtendedError, OrderRejectReason, Result};
     pub use crate::models::*;
     pub use crate::signing::{StarkSigner, sign_order};
-    pub use crate::{PublicOnlyClient, ReadOnlyClient, TradingClient, TradingClientBuilder};
+    pub use crate::{
+        PublicOnlyClient, ReadOnlyClient, ReduceOnlyCheck, TradingClient, TradingClientBuilder,
+    };
 }
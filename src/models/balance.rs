@@ -1,8 +1,13 @@
 //! Account and balance models.
 
+use std::collections::HashMap;
+use std::fmt;
+
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
 
+use super::{format_amount, mask_secret, Position, PositionSide};
+
 /// Helper to deserialize string numbers as Decimal.
 fn decimal_from_string<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
 where
@@ -19,6 +24,7 @@ where
 {
     let opt: Option<String> = Option::deserialize(deserializer)?;
     match opt {
+        Some(s) if s.is_empty() => Ok(None),
         Some(s) => s.parse::<Decimal>().map(Some).map_err(serde::de::Error::custom),
         None => Ok(None),
     }
@@ -46,6 +52,64 @@ pub struct ApiKeyInfo {
     pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
+impl ApiKeyInfo {
+    /// Get the permissions this SDK knows about, skipping any it doesn't recognize.
+    pub fn permission_list(&self) -> Vec<ApiKeyPermission> {
+        self.permissions
+            .iter()
+            .flatten()
+            .filter_map(|p| ApiKeyPermission::parse(p))
+            .collect()
+    }
+
+    /// Check whether the key has a permission.
+    pub fn has_permission(&self, permission: ApiKeyPermission) -> bool {
+        self.permission_list().contains(&permission)
+    }
+}
+
+/// Scope granted to an API key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiKeyPermission {
+    /// Read account data only.
+    ReadOnly,
+    /// Place and cancel orders.
+    Trade,
+    /// Withdraw collateral.
+    Withdraw,
+    /// Transfer between accounts.
+    Transfer,
+}
+
+impl ApiKeyPermission {
+    /// Parse a permission as the API spells it.
+    ///
+    /// Case, `-` and spaces are ignored, and common variants are accepted ("READ",
+    /// "TRADING", "WITHDRAWALS"). Returns `None` for permissions this SDK doesn't know,
+    /// so a new scope on the exchange doesn't break deserialization.
+    pub fn parse(permission: &str) -> Option<Self> {
+        let normalized = permission.trim().to_ascii_uppercase().replace(['-', ' '], "_");
+        match normalized.as_str() {
+            "READ" | "READ_ONLY" | "READONLY" | "VIEW" => Some(Self::ReadOnly),
+            "TRADE" | "TRADING" => Some(Self::Trade),
+            "WITHDRAW" | "WITHDRAWAL" | "WITHDRAWALS" => Some(Self::Withdraw),
+            "TRANSFER" | "TRANSFERS" => Some(Self::Transfer),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ApiKeyPermission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ReadOnly => "READ_ONLY",
+            Self::Trade => "TRADE",
+            Self::Withdraw => "WITHDRAW",
+            Self::Transfer => "TRANSFER",
+        })
+    }
+}
+
 /// Helper to deserialize optional api_keys array.
 fn deserialize_api_keys<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
 where
@@ -120,6 +184,44 @@ impl AccountInfo {
     }
 }
 
+/// Request to register a Stark key with the exchange (onboarding).
+///
+/// Built by `EthDerivedKey::registration`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StarkKeyRegistration {
+    /// Ethereum signature of `account_creation`, which the Stark key was derived from.
+    pub l1_signature: String,
+    /// Stark public key (hex).
+    pub l2_key: String,
+    /// Stark signature of the Pedersen hash of the wallet address and `l2_key`, proving
+    /// ownership of the key.
+    pub l2_signature: OnboardingSignature,
+    /// The `AccountCreation` message that was signed with the Ethereum key.
+    pub account_creation: AccountCreation,
+}
+
+/// Stark signature for onboarding.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OnboardingSignature {
+    /// Signature r component.
+    pub r: String,
+    /// Signature s component.
+    pub s: String,
+}
+
+/// The `AccountCreation` message signed with the Ethereum key during onboarding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountCreation {
+    /// Sub-account index (0 for the main account).
+    pub account_index: u8,
+    /// Checksummed address of the Ethereum account.
+    pub wallet: String,
+    /// Whether the terms of service were accepted (always true when signed by the SDK).
+    pub tos_accepted: bool,
+}
+
 /// Account status.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -218,7 +320,32 @@ impl Balance {
         self.account_leverage.unwrap_or(Decimal::ZERO)
     }
 
+    /// Format equity for display with the given number of decimal places.
+    ///
+    /// Use the market's `collateral_asset_precision` (see `Market::collateral_precision()`)
+    /// to match the exchange's display, e.g. 2 decimals for USD.
+    pub fn display_equity(&self, precision: u32) -> String {
+        format_amount(self.equity, precision)
+    }
+
+    /// Format account balance for display with the given number of decimal places.
+    pub fn display_balance(&self, precision: u32) -> String {
+        format_amount(self.balance, precision)
+    }
+
+    /// Format available-for-trade for display with the given number of decimal places.
+    pub fn display_available_for_trade(&self, precision: u32) -> String {
+        format_amount(self.get_available_for_trade(), precision)
+    }
+
+    /// Format available-for-withdrawal for display with the given number of decimal places.
+    pub fn display_available_for_withdrawal(&self, precision: u32) -> String {
+        format_amount(self.get_available_for_withdrawal(), precision)
+    }
+
     /// Check if the account is at risk of liquidation.
+    ///
+    /// Uses a fixed 80% margin ratio; see `risk_report` for a configurable threshold.
     pub fn is_at_risk(&self) -> bool {
         self.get_margin_ratio() >= Decimal::from(80) / Decimal::from(100)
     }
@@ -227,6 +354,98 @@ impl Balance {
     pub fn is_liquidating(&self) -> bool {
         self.get_margin_ratio() >= Decimal::ONE
     }
+
+    /// Summarize the account's liquidation risk with its open positions.
+    ///
+    /// The margin ratio is the API's `margin_ratio`, or maintenance margin over equity
+    /// when that's missing. Positions are ordered closest to liquidation first.
+    ///
+    /// # Arguments
+    /// * `positions` - Open positions of this account
+    /// * `warn_threshold` - Margin ratio at which the account counts as at risk (e.g. 0.8)
+    pub fn risk_report(&self, positions: &[Position], warn_threshold: Decimal) -> RiskReport {
+        let margin_ratio = self
+            .margin_ratio
+            .or_else(|| self.maintenance_margin?.checked_div(self.equity))
+            .unwrap_or(Decimal::ZERO);
+        let initial_margin_usage =
+            self.get_initial_margin().checked_div(self.equity).unwrap_or(Decimal::ZERO);
+
+        let mut rows: Vec<PositionRisk> = positions
+            .iter()
+            .map(|p| {
+                let liquidation_distance = p.liquidation_price.and_then(|liq| {
+                    let distance = match p.side {
+                        PositionSide::Long => p.mark_price - liq,
+                        PositionSide::Short => liq - p.mark_price,
+                    };
+                    distance.checked_div(p.mark_price)
+                });
+                PositionRisk {
+                    market: p.market.clone(),
+                    side: p.side,
+                    mark_price: p.mark_price,
+                    liquidation_price: p.liquidation_price,
+                    liquidation_distance,
+                }
+            })
+            .collect();
+        // Positions without a liquidation price sort last
+        rows.sort_by_key(|r| (r.liquidation_distance.is_none(), r.liquidation_distance));
+
+        RiskReport {
+            positions: rows,
+            margin_ratio,
+            initial_margin_usage,
+            warn_threshold,
+        }
+    }
+}
+
+/// Distance of a position from its liquidation price.
+#[derive(Debug, Clone)]
+pub struct PositionRisk {
+    /// Market name.
+    pub market: String,
+    /// Position side.
+    pub side: PositionSide,
+    /// Current mark price.
+    pub mark_price: Decimal,
+    /// Liquidation price, if the API reported one.
+    pub liquidation_price: Option<Decimal>,
+    /// How far the mark price can move against the position before liquidation, as a
+    /// fraction of the mark price (0.05 = 5%). Zero or negative once it's reached.
+    pub liquidation_distance: Option<Decimal>,
+}
+
+/// Account liquidation risk, from `Balance::risk_report`.
+#[derive(Debug, Clone)]
+pub struct RiskReport {
+    /// Per-position liquidation distance, closest to liquidation first.
+    pub positions: Vec<PositionRisk>,
+    /// Maintenance margin / equity; the account is liquidated at 1.
+    pub margin_ratio: Decimal,
+    /// Initial margin / equity: how much of the equity open positions tie up.
+    pub initial_margin_usage: Decimal,
+    /// Margin ratio at which the account counts as at risk.
+    pub warn_threshold: Decimal,
+}
+
+impl RiskReport {
+    /// Check if the margin ratio has reached the warning threshold.
+    pub fn is_at_risk(&self) -> bool {
+        self.margin_ratio >= self.warn_threshold
+    }
+
+    /// Check if the account is being liquidated.
+    pub fn is_liquidating(&self) -> bool {
+        self.margin_ratio >= Decimal::ONE
+    }
+
+    /// Get the position closest to liquidation, if any has a liquidation price.
+    pub fn closest_to_liquidation(&self) -> Option<&PositionRisk> {
+        self.positions.first().filter(|r| r.liquidation_distance.is_some())
+    }
 }
 
 /// Leverage configuration per market.
@@ -301,6 +520,25 @@ impl MarketFee {
     pub fn get_taker_fee_rate(&self) -> Decimal {
         self.taker_fee_rate.unwrap_or(Decimal::ZERO)
     }
+
+    /// Get the rate charged to a maker or taker fill.
+    pub fn rate(&self, is_maker: bool) -> Decimal {
+        if is_maker {
+            self.get_maker_fee_rate()
+        } else {
+            self.get_taker_fee_rate()
+        }
+    }
+
+    /// Estimate the fee (in collateral) of a fill, from its notional value.
+    ///
+    /// # Arguments
+    /// * `price` - Fill price
+    /// * `quantity` - Fill quantity
+    /// * `is_maker` - Whether the fill adds liquidity (e.g. a resting post-only order)
+    pub fn estimate(&self, price: Decimal, quantity: Decimal, is_maker: bool) -> Decimal {
+        price * quantity.abs() * self.rate(is_maker)
+    }
 }
 
 /// Legacy Fees type alias for backwards compatibility.
@@ -370,6 +608,23 @@ impl SpotBalances {
         self.total_notional_value() - self.total_equity_contribution()
     }
 
+    /// Get the borrowing power each asset provides (its equity contribution), keyed by asset.
+    pub fn borrowing_power_by_asset(&self) -> HashMap<String, Decimal> {
+        let mut map = HashMap::new();
+        for b in &self.0 {
+            *map.entry(b.asset.clone()).or_insert(Decimal::ZERO) += b.equity_contribution;
+        }
+        map
+    }
+
+    /// Get the asset with the highest contribution factor.
+    ///
+    /// This is the most capital-efficient asset to deposit as collateral,
+    /// since the smallest share of its notional value is lost to haircuts.
+    pub fn most_efficient_collateral(&self) -> Option<&SpotBalance> {
+        self.0.iter().max_by_key(|b| b.contribution_factor)
+    }
+
     /// Find balance for a specific asset.
     pub fn get(&self, asset: &str) -> Option<&SpotBalance> {
         self.0.iter().find(|b| b.asset == asset)
@@ -453,7 +708,9 @@ pub enum AssetOperationStatus {
 }
 
 /// Stark account credentials.
-#[derive(Debug, Clone)]
+///
+/// `Debug` output masks the API key and private key.
+#[derive(Clone)]
 pub struct StarkAccount {
     /// API key for authentication.
     pub api_key: String,
@@ -481,3 +738,156 @@ impl StarkAccount {
         }
     }
 }
+
+impl fmt::Debug for StarkAccount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StarkAccount")
+            .field("api_key", &mask_secret(&self.api_key))
+            .field("public_key", &self.public_key)
+            .field("private_key", &mask_secret(&self.private_key))
+            .field("vault_id", &self.vault_id)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_key_permissions() {
+        for (raw, expected) in [
+            ("READ_ONLY", ApiKeyPermission::ReadOnly),
+            ("read", ApiKeyPermission::ReadOnly),
+            ("Trade", ApiKeyPermission::Trade),
+            ("TRADING", ApiKeyPermission::Trade),
+            ("withdrawals", ApiKeyPermission::Withdraw),
+            ("transfer", ApiKeyPermission::Transfer),
+        ] {
+            assert_eq!(ApiKeyPermission::parse(raw), Some(expected), "{}", raw);
+        }
+        assert_eq!(ApiKeyPermission::parse("STAKE"), None);
+        assert_eq!(ApiKeyPermission::Withdraw.to_string(), "WITHDRAW");
+
+        let json = r#"{"apiKey": "key", "permissions": ["READ_ONLY", "TRADE", "NEW_SCOPE"]}"#;
+        let info: ApiKeyInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            info.permission_list(),
+            [ApiKeyPermission::ReadOnly, ApiKeyPermission::Trade]
+        );
+        assert!(info.has_permission(ApiKeyPermission::Trade));
+        assert!(!info.has_permission(ApiKeyPermission::Withdraw));
+
+        let info: ApiKeyInfo = serde_json::from_str(r#"{"apiKey": "key"}"#).unwrap();
+        assert!(info.permission_list().is_empty());
+    }
+
+    #[test]
+    fn test_balance_empty_strings_are_none() {
+        let json = r#"{
+            "collateralName": "USD",
+            "balance": "100.5",
+            "equity": "101.25",
+            "unrealizedPnl": "",
+            "initialMargin": "",
+            "availableForTrade": "80",
+            "marginRatio": ""
+        }"#;
+        let balance: Balance = serde_json::from_str(json).unwrap();
+        assert_eq!(balance.unrealized_pnl, None);
+        assert_eq!(balance.initial_margin, None);
+        assert_eq!(balance.margin_ratio, None);
+        assert_eq!(balance.available_for_trade, Some(Decimal::from(80)));
+    }
+
+    #[test]
+    fn test_stark_account_debug_redacts_secrets() {
+        let account = StarkAccount::new(
+            "a1b2c3d4e5f6a7b8",
+            "0x0abc",
+            "0x0123456789abcdef0123456789abcdef",
+            "10001",
+        );
+        let debug = format!("{:?}", account);
+        assert!(debug.contains("a1b2***"));
+        assert!(debug.contains("0x01***"));
+        assert!(!debug.contains("a1b2c3d4e5f6a7b8"));
+        assert!(!debug.contains("0123456789abcdef"));
+        assert!(debug.contains("10001"));
+    }
+
+    #[test]
+    fn test_risk_report() {
+        use rust_decimal_macros::dec;
+
+        fn position(market: &str, side: &str, mark: &str, liquidation: &str) -> Position {
+            serde_json::from_value(serde_json::json!({
+                "market": market,
+                "side": side,
+                "size": "1",
+                "openPrice": "100",
+                "markPrice": mark,
+                "liquidationPrice": liquidation,
+                "unrealisedPnl": "0",
+                "leverage": "10"
+            }))
+            .unwrap()
+        }
+        fn balance(margin_ratio: &str) -> Balance {
+            serde_json::from_value(serde_json::json!({
+                "balance": "1000",
+                "equity": "1000",
+                "initialMargin": "400",
+                "maintenanceMargin": "200",
+                "marginRatio": margin_ratio
+            }))
+            .unwrap()
+        }
+        let positions = [
+            position("BTC-USD", "LONG", "100", "80"),
+            position("ETH-USD", "SHORT", "100", "110"),
+            position("SOL-USD", "LONG", "100", ""),
+        ];
+
+        let healthy = balance("0.2").risk_report(&positions, dec!(0.8));
+        assert!(!healthy.is_at_risk());
+        assert!(!healthy.is_liquidating());
+        assert_eq!(healthy.initial_margin_usage, dec!(0.4));
+        let markets: Vec<&str> = healthy.positions.iter().map(|r| r.market.as_str()).collect();
+        assert_eq!(markets, ["ETH-USD", "BTC-USD", "SOL-USD"]);
+        assert_eq!(healthy.positions[0].liquidation_distance, Some(dec!(0.1)));
+        assert_eq!(healthy.positions[1].liquidation_distance, Some(dec!(0.2)));
+        assert_eq!(healthy.positions[2].liquidation_distance, None);
+
+        // Same account at a stricter threshold, and one about to be liquidated
+        assert!(balance("0.2").risk_report(&positions, dec!(0.2)).is_at_risk());
+        let near = [position("BTC-USD", "LONG", "81", "80")];
+        let report = balance("0.97").risk_report(&near, dec!(0.8));
+        assert!(report.is_at_risk());
+        assert!(!report.is_liquidating());
+        let closest = report.closest_to_liquidation().unwrap();
+        assert!(closest.liquidation_distance.unwrap() < dec!(0.013));
+
+        // Margin ratio falls back to maintenance margin / equity
+        let mut balance = balance("");
+        balance.equity = dec!(250);
+        assert_eq!(balance.risk_report(&[], dec!(0.8)).margin_ratio, dec!(0.8));
+        assert!(balance.risk_report(&[], dec!(0.8)).closest_to_liquidation().is_none());
+    }
+
+    #[test]
+    fn test_market_fee_estimate() {
+        use rust_decimal_macros::dec;
+
+        let fee: MarketFee = serde_json::from_str(
+            r#"{"market": "BTC-USD", "makerFeeRate": "0.0001", "takerFeeRate": "0.00045"}"#,
+        )
+        .unwrap();
+        assert_eq!(fee.rate(true), dec!(0.0001));
+        assert_eq!(fee.rate(false), dec!(0.00045));
+        // 0.5 BTC at 100,000 = 50,000 notional
+        assert_eq!(fee.estimate(dec!(100000), dec!(0.5), true), dec!(5));
+        assert_eq!(fee.estimate(dec!(100000), dec!(0.5), false), dec!(22.5));
+        assert_eq!(fee.estimate(dec!(100000), dec!(-0.5), false), dec!(22.5));
+    }
+}
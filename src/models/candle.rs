@@ -1,6 +1,6 @@
 //! Candlestick (OHLCV) models.
 
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, MathematicalOps};
 use serde::{Deserialize, Deserializer, Serialize};
 
 use super::TimeInterval;
@@ -112,6 +112,101 @@ impl Candle {
     }
 }
 
+/// Compute the annualized realized volatility of a candle series.
+///
+/// Takes the sample standard deviation of log returns between consecutive closes and
+/// scales it by `sqrt(annualization_factor)` (e.g., `8760` for hourly candles, `365`
+/// for daily). Candles are expected in chronological order.
+///
+/// Returns `None` if there are fewer than three candles or any close is not positive.
+pub fn realized_volatility(candles: &[Candle], annualization_factor: Decimal) -> Option<Decimal> {
+    if candles.len() < 3 || annualization_factor.is_sign_negative() {
+        return None;
+    }
+
+    let returns = candles
+        .windows(2)
+        .map(|w| {
+            if w[0].close <= Decimal::ZERO || w[1].close <= Decimal::ZERO {
+                None
+            } else {
+                (w[1].close / w[0].close).checked_ln()
+            }
+        })
+        .collect::<Option<Vec<Decimal>>>()?;
+
+    let n = Decimal::from(returns.len());
+    let mean = returns.iter().sum::<Decimal>() / n;
+    let variance = returns
+        .iter()
+        .map(|r| (*r - mean) * (*r - mean))
+        .sum::<Decimal>()
+        / (n - Decimal::ONE);
+
+    Some(variance.sqrt()? * annualization_factor.sqrt()?)
+}
+
+/// Weekly candles open on Monday 00:00 UTC; the Unix epoch was a Thursday.
+const WEEK_OFFSET_MILLIS: i64 = 4 * 86_400_000;
+
+/// Resample candles to a coarser interval (e.g. 1-minute candles to 1-hour).
+///
+/// Candles are grouped into buckets of the target interval by open time (UTC, weeks
+/// starting on Monday), taking the first open, highest high, lowest low and last
+/// close, and summing volumes and trade counts. Quote volume and trade count are only
+/// set when every candle in the bucket has them. Buckets with no candles are skipped
+/// rather than filled in, and the result is in chronological order whatever the
+/// order of the input.
+///
+/// Fails with `ExtendedError::InvalidParameter` if `to` is finer than `from`.
+///
+/// # Arguments
+/// * `candles` - Candles of the `from` interval
+/// * `from` - Interval of the input candles
+/// * `to` - Interval to resample to
+pub fn resample_candles(
+    candles: &[Candle],
+    from: TimeInterval,
+    to: TimeInterval,
+) -> crate::error::Result<Vec<Candle>> {
+    if to.millis() < from.millis() {
+        return Err(crate::error::ExtendedError::InvalidParameter(format!(
+            "cannot resample {} candles to the finer {} interval",
+            from.as_str(),
+            to.as_str()
+        )));
+    }
+
+    let offset = if to == TimeInterval::OneWeek { WEEK_OFFSET_MILLIS } else { 0 };
+    let bucket_start = |timestamp: i64| {
+        (timestamp - offset).div_euclid(to.millis()) * to.millis() + offset
+    };
+
+    let mut sorted: Vec<&Candle> = candles.iter().collect();
+    sorted.sort_by_key(|c| c.timestamp);
+
+    let mut resampled: Vec<Candle> = Vec::new();
+    for candle in sorted {
+        let start = bucket_start(candle.timestamp);
+        match resampled.last_mut() {
+            Some(bucket) if bucket.timestamp == start => {
+                bucket.high = bucket.high.max(candle.high);
+                bucket.low = bucket.low.min(candle.low);
+                bucket.close = candle.close;
+                bucket.volume += candle.volume;
+                bucket.quote_volume =
+                    bucket.quote_volume.zip(candle.quote_volume).map(|(a, b)| a + b);
+                bucket.trades = bucket.trades.zip(candle.trades).map(|(a, b)| a + b);
+            }
+            _ => resampled.push(Candle {
+                timestamp: start,
+                ..candle.clone()
+            }),
+        }
+    }
+    Ok(resampled)
+}
+
 /// Parameters for fetching candles.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -163,3 +258,109 @@ impl GetCandlesParams {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn candle(close: Decimal) -> Candle {
+        Candle {
+            timestamp: 0,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: Decimal::ZERO,
+            quote_volume: None,
+            trades: None,
+        }
+    }
+
+    fn minute(timestamp: i64, open: i64, close: i64) -> Candle {
+        Candle {
+            timestamp,
+            open: Decimal::from(open),
+            high: Decimal::from(open.max(close) + 1),
+            low: Decimal::from(open.min(close) - 1),
+            close: Decimal::from(close),
+            volume: dec!(0.5),
+            quote_volume: Some(Decimal::from(close) / dec!(2)),
+            trades: Some(3),
+        }
+    }
+
+    #[test]
+    fn test_resample_minutes_to_hours() {
+        const HOUR: i64 = 3_600_000;
+        // 2023-11-14 22:00 UTC
+        let start = 1_699_999_200_000;
+        // First hour complete, second hour missing, third hour half full
+        let mut candles: Vec<Candle> = (0..60)
+            .map(|m| minute(start + m * 60_000, 100 + m, 101 + m))
+            .chain((0..30).map(|m| minute(start + 2 * HOUR + m * 60_000, 200 - m, 199 - m)))
+            .collect();
+        // Input order doesn't matter
+        candles.reverse();
+
+        let hours =
+            resample_candles(&candles, TimeInterval::OneMinute, TimeInterval::OneHour).unwrap();
+        assert_eq!(hours.len(), 2);
+
+        let first = &hours[0];
+        assert_eq!(first.timestamp, start);
+        assert_eq!(first.open, dec!(100));
+        assert_eq!(first.high, dec!(161));
+        assert_eq!(first.low, dec!(99));
+        assert_eq!(first.close, dec!(160));
+        assert_eq!(first.volume, dec!(30));
+        // Sum of closes 101..=160, halved
+        assert_eq!(first.quote_volume, Some(dec!(3915)));
+        assert_eq!(first.trades, Some(180));
+
+        let third = &hours[1];
+        assert_eq!(third.timestamp, start + 2 * HOUR);
+        assert_eq!(third.open, dec!(200));
+        assert_eq!(third.high, dec!(201));
+        assert_eq!(third.low, dec!(169));
+        assert_eq!(third.close, dec!(170));
+        assert_eq!(third.volume, dec!(15));
+    }
+
+    #[test]
+    fn test_resample_partial_fields_weeks_and_finer_interval() {
+        let mut candles = vec![minute(0, 1, 2), minute(60_000, 2, 3)];
+        candles[1].quote_volume = None;
+        let five =
+            resample_candles(&candles, TimeInterval::OneMinute, TimeInterval::FiveMinutes)
+                .unwrap();
+        assert_eq!(five.len(), 1);
+        assert_eq!(five[0].quote_volume, None);
+        assert_eq!(five[0].trades, Some(6));
+
+        // The epoch was a Thursday, so its week opened on Monday 1969-12-29
+        let weeks =
+            resample_candles(&candles, TimeInterval::OneDay, TimeInterval::OneWeek).unwrap();
+        assert_eq!(weeks[0].timestamp, -3 * 86_400_000);
+
+        assert!(matches!(
+            resample_candles(&candles, TimeInterval::OneHour, TimeInterval::FiveMinutes),
+            Err(crate::error::ExtendedError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_realized_volatility() {
+        let flat: Vec<Candle> = [dec!(100), dec!(100), dec!(100)].map(candle).to_vec();
+        assert_eq!(realized_volatility(&flat, dec!(365)), Some(Decimal::ZERO));
+
+        let moving: Vec<Candle> = [dec!(100), dec!(110), dec!(100), dec!(110)].map(candle).to_vec();
+        let vol = realized_volatility(&moving, dec!(365)).unwrap();
+        // Returns alternate +/-ln(1.1) ~ 0.0953: sample stdev ~0.1101, annualized ~2.103.
+        assert!((vol - dec!(2.103)).abs() < dec!(0.001), "vol = {}", vol);
+
+        assert_eq!(realized_volatility(&moving[..2], dec!(365)), None);
+        let bad: Vec<Candle> = [dec!(100), dec!(0), dec!(100)].map(candle).to_vec();
+        assert_eq!(realized_volatility(&bad, dec!(365)), None);
+    }
+}
@@ -1,6 +1,6 @@
 //! Common types used across the SDK.
 
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Deserializer, Serialize};
 
 /// Helper to deserialize string numbers as Decimal.
@@ -12,6 +12,28 @@ where
     s.parse::<Decimal>().map_err(serde::de::Error::custom)
 }
 
+/// Format a decimal amount with a fixed number of decimal places.
+///
+/// Rounds half away from zero and pads with trailing zeros, so an amount of
+/// `1234.5` formatted with precision 2 becomes `"1234.50"` and `1234.500000000`
+/// becomes `"1234.50"`.
+pub fn format_amount(value: Decimal, precision: u32) -> String {
+    let mut rounded =
+        value.round_dp_with_strategy(precision, RoundingStrategy::MidpointAwayFromZero);
+    rounded.rescale(precision);
+    rounded.to_string()
+}
+
+/// Mask a secret for display, keeping at most a short prefix.
+///
+/// Values of 8 characters or fewer are fully masked.
+pub(crate) fn mask_secret(secret: &str) -> String {
+    match secret.get(..4) {
+        Some(prefix) if secret.len() > 8 => format!("{}***", prefix),
+        _ => "***".to_string(),
+    }
+}
+
 /// Pagination parameters for cursor-based pagination.
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct PaginationParams {
@@ -82,7 +104,7 @@ pub struct ApiResponse<T> {
 }
 
 /// Price-quantity pair used in orderbooks.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct PriceQuantity {
     /// Price level.
     #[serde(deserialize_with = "decimal_from_string")]
@@ -136,6 +158,21 @@ impl TimeInterval {
             Self::OneWeek => "P1W",
         }
     }
+
+    /// Get the interval length in milliseconds.
+    pub fn millis(&self) -> i64 {
+        const MINUTE: i64 = 60_000;
+        match self {
+            Self::OneMinute => MINUTE,
+            Self::FiveMinutes => 5 * MINUTE,
+            Self::FifteenMinutes => 15 * MINUTE,
+            Self::ThirtyMinutes => 30 * MINUTE,
+            Self::OneHour => 60 * MINUTE,
+            Self::FourHours => 240 * MINUTE,
+            Self::OneDay => 1440 * MINUTE,
+            Self::OneWeek => 7 * 1440 * MINUTE,
+        }
+    }
 }
 
 /// Candle type for different price sources.
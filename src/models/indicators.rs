@@ -0,0 +1,179 @@
+//! Technical indicators over candle series.
+//!
+//! Each indicator takes candles in chronological order and returns one value per
+//! candle, with `None` for the warmup candles before enough history is available
+//! (and for every candle when `period` is zero). All arithmetic is in `Decimal`.
+
+use rust_decimal::Decimal;
+
+use super::Candle;
+
+/// Simple moving average of the close over the last `period` candles.
+pub fn sma(candles: &[Candle], period: usize) -> Vec<Option<Decimal>> {
+    let mut values = vec![None; candles.len()];
+    if period == 0 {
+        return values;
+    }
+    let mut sum = Decimal::ZERO;
+    for (i, candle) in candles.iter().enumerate() {
+        sum += candle.close;
+        if i >= period {
+            sum -= candles[i - period].close;
+        }
+        if i + 1 >= period {
+            values[i] = Some(sum / Decimal::from(period));
+        }
+    }
+    values
+}
+
+/// Exponential moving average of the close, with smoothing factor `2 / (period + 1)`.
+///
+/// Seeded with the simple moving average of the first `period` closes.
+pub fn ema(candles: &[Candle], period: usize) -> Vec<Option<Decimal>> {
+    let mut values = vec![None; candles.len()];
+    if period == 0 || candles.len() < period {
+        return values;
+    }
+    let k = Decimal::TWO / Decimal::from(period + 1);
+    let seed = candles[..period].iter().map(|c| c.close).sum::<Decimal>() / Decimal::from(period);
+    let mut ema = seed;
+    values[period - 1] = Some(seed);
+    for (i, candle) in candles.iter().enumerate().skip(period) {
+        ema += (candle.close - ema) * k;
+        values[i] = Some(ema);
+    }
+    values
+}
+
+/// Relative strength index (0-100) of the close, using Wilder's smoothing.
+///
+/// The first value is at index `period`, from the average gain and loss of the first
+/// `period` close-to-close changes. A series with no losses is 100, and one with no
+/// changes at all is 50.
+pub fn rsi(candles: &[Candle], period: usize) -> Vec<Option<Decimal>> {
+    let mut values = vec![None; candles.len()];
+    if period == 0 || candles.len() <= period {
+        return values;
+    }
+    let n = Decimal::from(period);
+    let changes: Vec<Decimal> = candles.windows(2).map(|w| w[1].close - w[0].close).collect();
+    let gain = |change: Decimal| change.max(Decimal::ZERO);
+    let loss = |change: Decimal| (-change).max(Decimal::ZERO);
+
+    let mut avg_gain = changes[..period].iter().copied().map(gain).sum::<Decimal>() / n;
+    let mut avg_loss = changes[..period].iter().copied().map(loss).sum::<Decimal>() / n;
+    values[period] = Some(rsi_value(avg_gain, avg_loss));
+    for (i, change) in changes.iter().copied().enumerate().skip(period) {
+        avg_gain = (avg_gain * (n - Decimal::ONE) + gain(change)) / n;
+        avg_loss = (avg_loss * (n - Decimal::ONE) + loss(change)) / n;
+        values[i + 1] = Some(rsi_value(avg_gain, avg_loss));
+    }
+    values
+}
+
+fn rsi_value(avg_gain: Decimal, avg_loss: Decimal) -> Decimal {
+    let hundred = Decimal::ONE_HUNDRED;
+    match (avg_gain.is_zero(), avg_loss.is_zero()) {
+        (true, true) => hundred / Decimal::TWO,
+        (_, true) => hundred,
+        _ => hundred - hundred / (Decimal::ONE + avg_gain / avg_loss),
+    }
+}
+
+/// Average true range, using Wilder's smoothing.
+///
+/// The true range of a candle is the largest of its range and the distances from the
+/// previous close to its high and low, so it needs a previous candle: the first value
+/// is at index `period`, averaging the true ranges of candles 1 to `period`.
+pub fn atr(candles: &[Candle], period: usize) -> Vec<Option<Decimal>> {
+    let mut values = vec![None; candles.len()];
+    if period == 0 || candles.len() <= period {
+        return values;
+    }
+    let n = Decimal::from(period);
+    let true_ranges: Vec<Decimal> = candles
+        .windows(2)
+        .map(|w| {
+            let prev_close = w[0].close;
+            w[1].range()
+                .max((w[1].high - prev_close).abs())
+                .max((w[1].low - prev_close).abs())
+        })
+        .collect();
+
+    let mut atr = true_ranges[..period].iter().sum::<Decimal>() / n;
+    values[period] = Some(atr);
+    for (i, tr) in true_ranges.iter().enumerate().skip(period) {
+        atr = (atr * (n - Decimal::ONE) + tr) / n;
+        values[i + 1] = Some(atr);
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn candle(high: Decimal, low: Decimal, close: Decimal) -> Candle {
+        Candle {
+            timestamp: 0,
+            open: close,
+            high,
+            low,
+            close,
+            volume: Decimal::ZERO,
+            quote_volume: None,
+            trades: None,
+        }
+    }
+
+    fn closes(closes: &[Decimal]) -> Vec<Candle> {
+        closes.iter().map(|c| candle(*c, *c, *c)).collect()
+    }
+
+    #[test]
+    fn test_moving_averages() {
+        let candles = closes(&[dec!(10), dec!(11), dec!(12), dec!(16), dec!(14)]);
+        assert_eq!(
+            sma(&candles, 3),
+            vec![None, None, Some(dec!(11)), Some(dec!(13)), Some(dec!(14))]
+        );
+        // k = 0.5: 11, then 11 + (16 - 11) / 2, then 13.5 + (14 - 13.5) / 2
+        assert_eq!(
+            ema(&candles, 3),
+            vec![None, None, Some(dec!(11)), Some(dec!(13.5)), Some(dec!(13.75))]
+        );
+        assert_eq!(sma(&candles, 0), vec![None; 5]);
+        assert_eq!(ema(&candles, 6), vec![None; 5]);
+    }
+
+    #[test]
+    fn test_rsi() {
+        // Changes: +1, +1, +4, -2.5
+        let candles = closes(&[dec!(10), dec!(11), dec!(12), dec!(16), dec!(13.5)]);
+        // Gains average 1, 2.5, 1.25 and losses 0, 0, 1.25
+        assert_eq!(
+            rsi(&candles, 2),
+            vec![None, None, Some(dec!(100)), Some(dec!(100)), Some(dec!(50))]
+        );
+        assert_eq!(rsi(&closes(&[dec!(5); 3]), 2)[2], Some(dec!(50)));
+        assert_eq!(rsi(&candles[..2], 2), vec![None; 2]);
+    }
+
+    #[test]
+    fn test_atr() {
+        let candles = vec![
+            candle(dec!(12), dec!(8), dec!(10)),
+            candle(dec!(13), dec!(10), dec!(12)), // TR 3
+            candle(dec!(15), dec!(11), dec!(14)), // TR 4
+            candle(dec!(14), dec!(9), dec!(10)),  // TR 5
+            candle(dec!(18), dec!(12), dec!(17)), // TR 8 (gap up from 10)
+        ];
+        assert_eq!(
+            atr(&candles, 2),
+            vec![None, None, Some(dec!(3.5)), Some(dec!(4.25)), Some(dec!(6.125))]
+        );
+    }
+}
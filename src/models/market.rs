@@ -3,7 +3,8 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
 
-use super::PriceQuantity;
+use super::{format_amount, OrderSide, OrderType, PriceQuantity};
+use crate::error::ExtendedError;
 
 /// Helper to deserialize string numbers as Decimal.
 fn decimal_from_string<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
@@ -54,6 +55,39 @@ pub struct L2Config {
     pub synthetic_resolution: i64,
 }
 
+impl L2Config {
+    /// Get the collateral asset settings.
+    pub fn collateral(&self) -> CollateralConfig {
+        CollateralConfig {
+            asset_id: self.collateral_id.clone(),
+            resolution: self.collateral_resolution,
+        }
+    }
+}
+
+/// Collateral asset settings for signing transfers, withdrawals and order amounts.
+///
+/// Fetch it with `PublicApi::get_collateral_config` or take it from any market with
+/// `Market::collateral_config`; every market settles in the same collateral.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollateralConfig {
+    /// Collateral asset ID (hex string, e.g., "0x1" for USDC).
+    pub asset_id: String,
+    /// Collateral asset resolution (10^decimals, e.g., 1000000 for 6 decimals).
+    pub resolution: i64,
+}
+
+impl Default for CollateralConfig {
+    /// USDC as deployed on Extended's mainnet and testnet, for when the config can't be
+    /// fetched. Prefer the fetched config, which follows any change on the exchange.
+    fn default() -> Self {
+        Self {
+            asset_id: "0x1".to_string(),
+            resolution: 1_000_000,
+        }
+    }
+}
+
 /// Market information.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -97,6 +131,51 @@ impl Market {
         &self.collateral_asset_name
     }
 
+    /// Get one tick as basis points of a reference price (`tick_size / price * 10000`).
+    ///
+    /// This is the finest spread achievable in the market at that price.
+    /// Returns zero if the reference price is not positive.
+    pub fn tick_bps(&self, reference_price: Decimal) -> Decimal {
+        self.trading_config.tick_bps(reference_price)
+    }
+
+    /// Get collateral asset precision (decimal places used for display).
+    pub fn collateral_precision(&self) -> u32 {
+        self.collateral_asset_precision
+    }
+
+    /// Format a collateral amount (balance, PnL, notional) using the market's collateral precision.
+    pub fn format_collateral(&self, amount: Decimal) -> String {
+        format_amount(amount, self.collateral_asset_precision)
+    }
+
+    /// Format a base asset quantity using the market's asset precision.
+    pub fn format_asset(&self, amount: Decimal) -> String {
+        format_amount(amount, self.asset_precision)
+    }
+
+    /// Normalize a price for an order: round down to the tick size, truncate to the
+    /// collateral precision and drop trailing zeros.
+    ///
+    /// The result serializes the same however the input was written (`0.0100` and `0.01`
+    /// both give `"0.01"`). Round up first with `round_price_up` where rounding down would
+    /// be on the wrong side, e.g. for a sell's worst acceptable price.
+    pub fn normalize_price(&self, price: Decimal) -> Decimal {
+        self.trading_config
+            .round_price_down(price)
+            .trunc_with_scale(self.collateral_asset_precision)
+            .normalize()
+    }
+
+    /// Normalize a quantity for an order: round down to the step size, truncate to the
+    /// asset precision and drop trailing zeros.
+    pub fn normalize_quantity(&self, quantity: Decimal) -> Decimal {
+        self.trading_config
+            .round_qty_down(quantity)
+            .trunc_with_scale(self.asset_precision)
+            .normalize()
+    }
+
     /// Get the trading config (alias for trading_config).
     pub fn config(&self) -> &MarketConfig {
         &self.trading_config
@@ -131,6 +210,49 @@ impl Market {
     pub fn collateral_resolution(&self) -> i64 {
         self.l2_config.collateral_resolution
     }
+
+    /// Get the collateral asset settings for signing transfers and withdrawals.
+    pub fn collateral_config(&self) -> CollateralConfig {
+        self.l2_config.collateral()
+    }
+
+    /// Compute the collateral needed to open a position of `notional` at `leverage`.
+    ///
+    /// The requirement is `notional / leverage`, with leverage capped at the risk tier
+    /// limit for that notional (`MarketConfig::max_leverage_for_notional`). If the
+    /// requested leverage is above the limit, the capped requirement is returned and
+    /// `clamped` is set.
+    pub fn collateral_for_position(
+        &self,
+        notional: Decimal,
+        leverage: Decimal,
+    ) -> CollateralRequirement {
+        let max_leverage = self.trading_config.max_leverage_for_notional(notional.abs());
+        let clamped = leverage > max_leverage || leverage <= Decimal::ZERO;
+        let leverage = if clamped { max_leverage } else { leverage };
+        let collateral = if leverage.is_zero() {
+            notional.abs()
+        } else {
+            notional.abs() / leverage
+        };
+
+        CollateralRequirement {
+            collateral,
+            leverage,
+            clamped,
+        }
+    }
+}
+
+/// Collateral required to open a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollateralRequirement {
+    /// Collateral (USD) needed at `leverage`.
+    pub collateral: Decimal,
+    /// Leverage the requirement was computed at (after clamping).
+    pub leverage: Decimal,
+    /// Whether the requested leverage exceeded the tier limit and was clamped.
+    pub clamped: bool,
 }
 
 /// Market status.
@@ -211,6 +333,16 @@ impl MarketConfig {
         self.min_order_size_change
     }
 
+    /// Get one tick as basis points of a reference price (`tick_size / price * 10000`).
+    ///
+    /// Returns zero if the reference price is not positive.
+    pub fn tick_bps(&self, reference_price: Decimal) -> Decimal {
+        if reference_price <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        self.min_price_change / reference_price * Decimal::from(10_000)
+    }
+
     /// Round a price down to the market's tick size.
     pub fn round_price_down(&self, price: Decimal) -> Decimal {
         (price / self.min_price_change).floor() * self.min_price_change
@@ -231,6 +363,79 @@ impl MarketConfig {
         (quantity / self.min_order_size_change).ceil() * self.min_order_size_change
     }
 
+    /// Get the maximum leverage allowed for a position of the given notional value.
+    ///
+    /// Uses the first risk tier whose `upper_bound` covers the notional (leverage is
+    /// `1 / risk_factor`); notionals above every tier use the last, riskiest tier.
+    /// Never exceeds the market's `max_leverage`, which is also the fallback when the
+    /// market has no tiers.
+    pub fn max_leverage_for_notional(&self, notional: Decimal) -> Decimal {
+        let tier = self
+            .risk_factor_config
+            .iter()
+            .find(|tier| notional <= tier.upper_bound)
+            .or_else(|| self.risk_factor_config.last());
+
+        match tier {
+            Some(tier) if !tier.risk_factor.is_zero() => {
+                (Decimal::ONE / tier.risk_factor).min(self.max_leverage)
+            }
+            _ => self.max_leverage,
+        }
+    }
+
+    /// Check an order's price and quantity against the market constraints.
+    ///
+    /// Verifies the price is positive, the minimum order size, tick and step alignment,
+    /// and the maximum order value (market or limit, by order type). Returns
+    /// `ExtendedError::OrderValidation` describing the first violation, e.g.
+    /// "price 93050.21 not aligned to tick 0.1".
+    pub fn validate_order(
+        &self,
+        price: Decimal,
+        quantity: Decimal,
+        order_type: OrderType,
+    ) -> crate::error::Result<()> {
+        let invalid = |msg: String| Err(ExtendedError::OrderValidation(msg));
+
+        if price <= Decimal::ZERO {
+            return invalid(format!("price {} must be positive", price));
+        }
+        if quantity < self.min_order_size {
+            return invalid(format!(
+                "quantity {} below minimum order size {}",
+                quantity, self.min_order_size
+            ));
+        }
+        if !self.min_price_change.is_zero() && !(price % self.min_price_change).is_zero() {
+            return invalid(format!(
+                "price {} not aligned to tick {}",
+                price, self.min_price_change
+            ));
+        }
+        if !self.min_order_size_change.is_zero()
+            && !(quantity % self.min_order_size_change).is_zero()
+        {
+            return invalid(format!(
+                "quantity {} not aligned to step {}",
+                quantity, self.min_order_size_change
+            ));
+        }
+
+        let max_value = match order_type {
+            OrderType::Market => self.max_market_order_value,
+            _ => self.max_limit_order_value,
+        };
+        let value = price * quantity;
+        if value > max_value {
+            return invalid(format!(
+                "order value {} exceeds maximum {}",
+                value, max_value
+            ));
+        }
+        Ok(())
+    }
+
     /// Get the number of decimal places for prices.
     pub fn price_precision(&self) -> u32 {
         self.min_price_change.scale()
@@ -246,7 +451,7 @@ impl MarketConfig {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MarketStats {
-    /// Market name (only present when fetched directly via get_market_stats).
+    /// Market name (present when fetched via get_market_stats or get_all_market_stats).
     #[serde(default)]
     pub market: Option<String>,
     /// Current mark price.
@@ -291,9 +496,26 @@ pub struct MarketStats {
     /// Current funding rate (hourly).
     #[serde(default, deserialize_with = "option_decimal_from_string")]
     pub funding_rate: Option<Decimal>,
-    /// Next funding rate timestamp (Unix ms).
-    #[serde(default)]
-    pub next_funding_rate: Option<i64>,
+    /// Timestamp of the next funding event (Unix ms).
+    ///
+    /// The API names this field `nextFundingRate`, but it carries a timestamp,
+    /// not a rate. Use `funding_rate` for the current rate.
+    #[serde(default, alias = "nextFundingRate")]
+    pub next_funding_time: Option<i64>,
+}
+
+impl MarketStats {
+    /// Timestamp of the next funding event (Unix ms).
+    #[deprecated(note = "this is a timestamp, not a rate; use `next_funding_time`")]
+    pub fn next_funding_rate(&self) -> Option<i64> {
+        self.next_funding_time
+    }
+
+    /// Get the current funding rate annualized (hourly rate * 24 * 365).
+    pub fn annualized_funding_rate(&self) -> Option<Decimal> {
+        self.funding_rate
+            .map(|rate| rate * Decimal::from(FUNDING_PERIODS_PER_YEAR))
+    }
 }
 
 /// Order book snapshot.
@@ -338,6 +560,125 @@ impl OrderBook {
             _ => None,
         }
     }
+
+    /// Get the levels a taker order on `side` would consume (asks for buys, bids for sells).
+    pub fn levels_for(&self, side: OrderSide) -> &[PriceQuantity] {
+        match side {
+            OrderSide::Buy => &self.asks,
+            OrderSide::Sell => &self.bids,
+        }
+    }
+
+    /// Get the average fill price of a market order of `quantity` walking the book.
+    ///
+    /// Returns `None` if the quantity isn't positive or the book is too thin to fill it
+    /// (including when the side is empty).
+    pub fn fill_price(&self, side: OrderSide, quantity: Decimal) -> Option<Decimal> {
+        if quantity <= Decimal::ZERO {
+            return None;
+        }
+        let mut remaining = quantity;
+        let mut cost = Decimal::ZERO;
+        for level in self.levels_for(side) {
+            let take = remaining.min(level.quantity);
+            cost += take * level.price;
+            remaining -= take;
+            if remaining.is_zero() {
+                return Some(cost / quantity);
+            }
+        }
+        None
+    }
+
+    /// Get the quantity a taker order on `side` could fill at or better than `price_limit`.
+    ///
+    /// Returns zero if the side is empty or no level is within the limit.
+    pub fn depth_within(&self, side: OrderSide, price_limit: Decimal) -> Decimal {
+        self.levels_within(side, price_limit).map(|level| level.quantity).sum()
+    }
+
+    /// Get the notional (price × quantity) available to a taker order on `side` at or
+    /// better than `price_limit`.
+    ///
+    /// Returns zero if the side is empty or no level is within the limit.
+    pub fn notional_within(&self, side: OrderSide, price_limit: Decimal) -> Decimal {
+        self.levels_within(side, price_limit)
+            .map(|level| level.price * level.quantity)
+            .sum()
+    }
+
+    fn levels_within(
+        &self,
+        side: OrderSide,
+        price_limit: Decimal,
+    ) -> impl Iterator<Item = &PriceQuantity> {
+        self.levels_for(side).iter().take_while(move |level| match side {
+            OrderSide::Buy => level.price <= price_limit,
+            OrderSide::Sell => level.price >= price_limit,
+        })
+    }
+
+    /// Propose child order sizes for executing `total_quantity` within a slippage budget.
+    ///
+    /// Each child is sized so its average fill price, walking the current book, stays
+    /// within `max_slippage_bps` of the best price on the side it takes from. Children
+    /// are the largest size that fits the budget, with the remainder last, e.g.
+    /// `[2, 2, 1]` for 5 units when 2 fit. This assumes the book refills to its current
+    /// shape between children, so space them out accordingly.
+    ///
+    /// Returns an empty schedule if `total_quantity` isn't positive or the side is empty.
+    /// Sizes are not rounded to the market's step size.
+    pub fn suggest_slices(
+        &self,
+        side: OrderSide,
+        total_quantity: Decimal,
+        max_slippage_bps: Decimal,
+    ) -> Vec<Decimal> {
+        let levels = self.levels_for(side);
+        let Some(best) = levels.first().map(|level| level.price) else {
+            return Vec::new();
+        };
+        if total_quantity <= Decimal::ZERO {
+            return Vec::new();
+        }
+
+        let budget = max_slippage_bps.max(Decimal::ZERO) / Decimal::from(10_000);
+        let limit = match side {
+            OrderSide::Buy => best * (Decimal::ONE + budget),
+            OrderSide::Sell => best * (Decimal::ONE - budget),
+        };
+
+        // Largest size whose average price stays at or better than `limit`
+        let mut max_child = Decimal::ZERO;
+        let mut cost = Decimal::ZERO;
+        for level in levels {
+            let within = match side {
+                OrderSide::Buy => level.price <= limit,
+                OrderSide::Sell => level.price >= limit,
+            };
+            if within {
+                max_child += level.quantity;
+                cost += level.quantity * level.price;
+                continue;
+            }
+            // Take just enough of this level to bring the average to the limit
+            let partial = (limit * max_child - cost) / (level.price - limit);
+            max_child += partial.min(level.quantity).max(Decimal::ZERO);
+            break;
+        }
+        if max_child.is_zero() {
+            return Vec::new();
+        }
+
+        let mut slices = Vec::new();
+        let mut remaining = total_quantity;
+        while remaining > Decimal::ZERO {
+            let child = remaining.min(max_child);
+            slices.push(child);
+            remaining -= child;
+        }
+        slices
+    }
 }
 
 /// Funding rate information.
@@ -353,6 +694,38 @@ pub struct FundingRate {
     pub funding_time: i64,
 }
 
+/// Number of hourly funding periods in a year, used to annualize funding rates.
+pub const FUNDING_PERIODS_PER_YEAR: u32 = 24 * 365;
+
+/// Per-market funding snapshot used for funding-arbitrage screening.
+#[derive(Debug, Clone)]
+pub struct FundingScreenRow {
+    /// Market name.
+    pub market: String,
+    /// Current funding rate (hourly).
+    pub funding_rate: Option<Decimal>,
+    /// Funding rate annualized (hourly rate * 24 * 365).
+    pub annualized_rate: Option<Decimal>,
+    /// Open interest in quote asset.
+    pub open_interest: Option<Decimal>,
+    /// Current mark price.
+    pub mark_price: Decimal,
+}
+
+impl FundingScreenRow {
+    /// Build a screening row from a market's embedded statistics.
+    pub fn from_market(market: &Market) -> Self {
+        let stats = market.stats();
+        Self {
+            market: market.name.clone(),
+            funding_rate: stats.funding_rate,
+            annualized_rate: stats.annualized_funding_rate(),
+            open_interest: stats.open_interest,
+            mark_price: stats.mark_price,
+        }
+    }
+}
+
 /// Open interest data point.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -373,3 +746,226 @@ pub struct GetMarketsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<MarketStatus>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_funding_time_from_api_field() {
+        let json = r#"{
+            "markPrice": "100000",
+            "indexPrice": "100010",
+            "fundingRate": "-0.00001",
+            "nextFundingRate": 1701563440000
+        }"#;
+        let stats: MarketStats = serde_json::from_str(json).unwrap();
+        assert_eq!(stats.next_funding_time, Some(1_701_563_440_000));
+        assert_eq!(stats.funding_rate, Some(Decimal::new(-1, 5)));
+    }
+
+    fn btc_market() -> Market {
+        serde_json::from_value(serde_json::json!({
+            "name": "BTC-USD",
+            "assetName": "BTC",
+            "assetPrecision": 5,
+            "collateralAssetName": "USD",
+            "collateralAssetPrecision": 6,
+            "active": true,
+            "status": "ACTIVE",
+            "tradingConfig": {
+                "minOrderSize": "0.0001",
+                "minOrderSizeChange": "0.00001",
+                "minPriceChange": "1",
+                "maxMarketOrderValue": "1000000",
+                "maxLimitOrderValue": "5000000",
+                "maxPositionValue": "10000000",
+                "maxLeverage": "50",
+                "maxNumOrders": "200",
+                "limitPriceCap": "0.05",
+                "limitPriceFloor": "0.05",
+                "riskFactorConfig": [
+                    { "upperBound": "400000", "riskFactor": "0.02" },
+                    { "upperBound": "800000", "riskFactor": "0.04" },
+                    { "upperBound": "1000000000", "riskFactor": "0.1" }
+                ]
+            },
+            "marketStats": { "markPrice": "100000", "indexPrice": "100000" },
+            "l2Config": {
+                "type": "STARKNET",
+                "collateralId": "0x1",
+                "collateralResolution": 1000000,
+                "syntheticId": "0x4254432d3600000000000000000000",
+                "syntheticResolution": 1000000
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_collateral_for_position() {
+        let market = btc_market();
+
+        let req = market.collateral_for_position(Decimal::from(50_000), Decimal::from(10));
+        assert_eq!(req.collateral, Decimal::from(5_000));
+        assert!(!req.clamped);
+
+        // 600k sits in the 25x tier, so 50x is clamped
+        let req = market.collateral_for_position(Decimal::from(600_000), Decimal::from(50));
+        assert_eq!(req.leverage, Decimal::from(25));
+        assert_eq!(req.collateral, Decimal::from(24_000));
+        assert!(req.clamped);
+    }
+
+    #[test]
+    fn test_max_leverage_for_notional_across_tiers() {
+        use rust_decimal_macros::dec;
+
+        let mut config = btc_market().trading_config;
+        assert_eq!(config.max_leverage_for_notional(Decimal::ZERO), dec!(50));
+        // Upper bounds are inclusive
+        assert_eq!(config.max_leverage_for_notional(dec!(400000)), dec!(50));
+        assert_eq!(config.max_leverage_for_notional(dec!(400000.01)), dec!(25));
+        assert_eq!(config.max_leverage_for_notional(dec!(800000)), dec!(25));
+        assert_eq!(config.max_leverage_for_notional(dec!(800001)), dec!(10));
+        // Above every tier: the last tier applies
+        assert_eq!(config.max_leverage_for_notional(dec!(2000000000)), dec!(10));
+
+        // A tier looser than the market cap is clamped to it
+        config.max_leverage = dec!(20);
+        assert_eq!(config.max_leverage_for_notional(Decimal::ZERO), dec!(20));
+        assert_eq!(config.max_leverage_for_notional(dec!(800001)), dec!(10));
+
+        config.risk_factor_config.clear();
+        assert_eq!(config.max_leverage_for_notional(dec!(600000)), dec!(20));
+    }
+
+    #[test]
+    fn test_normalize_price_and_quantity() {
+        use rust_decimal_macros::dec;
+
+        let mut market = btc_market();
+        let config = market.trading_config.clone();
+
+        let price = market.normalize_price(dec!(95000.75));
+        assert_eq!(price, config.round_price_down(dec!(95000.75)));
+        assert_eq!(price.to_string(), "95000");
+
+        // Same value, different scales in: same scale out
+        market.trading_config.min_price_change = dec!(0.0001);
+        let a = market.normalize_price(dec!(0.0100));
+        let b = market.normalize_price(dec!(0.01));
+        assert_eq!((a.to_string(), b.to_string()), ("0.01".to_string(), "0.01".to_string()));
+        assert_eq!(a.scale(), 2);
+
+        // A tick finer than the declared precision is truncated to the precision
+        market.trading_config.min_price_change = dec!(0.0000001);
+        market.collateral_asset_precision = 6;
+        assert_eq!(market.normalize_price(dec!(1.2345678)).to_string(), "1.234567");
+
+        let quantity = market.normalize_quantity(dec!(0.123456789));
+        assert_eq!(quantity, config.round_qty_down(dec!(0.123456789)));
+        assert_eq!(quantity.to_string(), "0.12345");
+        assert_eq!(market.normalize_quantity(dec!(0.01000)).to_string(), "0.01");
+        market.asset_precision = 3;
+        assert_eq!(market.normalize_quantity(dec!(0.123456789)).to_string(), "0.123");
+    }
+
+    #[test]
+    fn test_validate_order() {
+        use rust_decimal_macros::dec;
+
+        let mut config = btc_market().trading_config;
+        config.min_price_change = dec!(0.1);
+        let check = |price, qty, order_type| {
+            config
+                .validate_order(price, qty, order_type)
+                .map_err(|e| e.to_string())
+        };
+
+        assert!(check(dec!(93050.2), dec!(0.01), OrderType::Limit).is_ok());
+        assert_eq!(
+            check(dec!(93050.21), dec!(0.01), OrderType::Limit).unwrap_err(),
+            "Order validation error: price 93050.21 not aligned to tick 0.1"
+        );
+        assert_eq!(
+            check(dec!(93050), dec!(0.00005), OrderType::Limit).unwrap_err(),
+            "Order validation error: quantity 0.00005 below minimum order size 0.0001"
+        );
+        assert_eq!(
+            check(dec!(93050), dec!(0.012345), OrderType::Limit).unwrap_err(),
+            "Order validation error: quantity 0.012345 not aligned to step 0.00001"
+        );
+        assert_eq!(
+            check(dec!(100000), dec!(20), OrderType::Market).unwrap_err(),
+            "Order validation error: order value 2000000 exceeds maximum 1000000"
+        );
+        assert!(check(dec!(100000), dec!(20), OrderType::Limit).is_ok());
+    }
+
+    #[test]
+    fn test_suggest_slices_within_slippage_budget() {
+        let level = |price: i64, quantity: i64| PriceQuantity {
+            price: Decimal::from(price),
+            quantity: Decimal::from(quantity),
+        };
+        let book = OrderBook {
+            market: "BTC-USD".to_string(),
+            bids: vec![level(100, 3), level(96, 10)],
+            asks: vec![level(100, 1), level(101, 1), level(103, 5)],
+            timestamp: 0,
+            sequence: None,
+        };
+
+        // 50 bps above 100 is 100.5: 1 @ 100 + 1 @ 101 averages exactly 100.5
+        let slices = book.suggest_slices(OrderSide::Buy, Decimal::from(5), Decimal::from(50));
+        assert_eq!(slices, vec![Decimal::from(2), Decimal::from(2), Decimal::ONE]);
+        for slice in &slices {
+            let impact = book.fill_price(OrderSide::Buy, *slice).unwrap();
+            assert!(impact <= Decimal::new(1005, 1));
+        }
+
+        // 1% below 100 is 99: 3 @ 100 + 1 @ 96 averages exactly 99
+        let slices = book.suggest_slices(OrderSide::Sell, Decimal::from(9), Decimal::from(100));
+        assert_eq!(slices, vec![Decimal::from(4), Decimal::from(4), Decimal::ONE]);
+
+        assert_eq!(book.fill_price(OrderSide::Buy, Decimal::from(8)), None);
+        assert!(book.suggest_slices(OrderSide::Buy, Decimal::ZERO, Decimal::from(50)).is_empty());
+    }
+
+    #[test]
+    fn test_fill_price_and_depth() {
+        use rust_decimal_macros::dec;
+
+        let level = |price: Decimal, quantity: Decimal| PriceQuantity { price, quantity };
+        let book = OrderBook {
+            market: "BTC-USD".to_string(),
+            bids: vec![level(dec!(99), dec!(2)), level(dec!(98), dec!(3))],
+            asks: vec![
+                level(dec!(100), dec!(1)),
+                level(dec!(101), dec!(2)),
+                level(dec!(105), dec!(1)),
+            ],
+            timestamp: 0,
+            sequence: None,
+        };
+
+        // Weighted across levels: (1 * 100 + 2 * 101) / 3
+        assert_eq!(book.fill_price(OrderSide::Buy, dec!(1)), Some(dec!(100)));
+        assert_eq!(book.fill_price(OrderSide::Buy, dec!(3)).unwrap().round_dp(4), dec!(100.6667));
+        // Partial use of the second level: (2 * 99 + 1 * 98) / 3
+        assert_eq!(book.fill_price(OrderSide::Sell, dec!(3)).unwrap().round_dp(4), dec!(98.6667));
+        assert_eq!(book.fill_price(OrderSide::Buy, dec!(5)), None);
+        assert_eq!(book.fill_price(OrderSide::Buy, Decimal::ZERO), None);
+
+        assert_eq!(book.depth_within(OrderSide::Buy, dec!(101)), dec!(3));
+        assert_eq!(book.notional_within(OrderSide::Buy, dec!(101)), dec!(302));
+        assert_eq!(book.notional_within(OrderSide::Sell, dec!(98.5)), dec!(198));
+        assert_eq!(book.depth_within(OrderSide::Sell, dec!(100)), Decimal::ZERO);
+
+        let empty = OrderBook { asks: Vec::new(), ..book };
+        assert_eq!(empty.fill_price(OrderSide::Buy, dec!(1)), None);
+        assert_eq!(empty.depth_within(OrderSide::Buy, dec!(1000)), Decimal::ZERO);
+        assert_eq!(empty.notional_within(OrderSide::Buy, dec!(1000)), Decimal::ZERO);
+    }
+}
@@ -0,0 +1,170 @@
+//! Validated market names.
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ExtendedError, Result};
+
+/// Market name in the exchange's `BASE-QUOTE` shape (e.g. "BTC-USD", "1000PEPE-USD").
+///
+/// Parsing checks the shape, so a typo like "BTCUSD" or "btc-usd" fails up front instead
+/// of as an API error. It doesn't check that the market exists.
+///
+/// API methods taking a market accept either this or a plain `&str` (through
+/// `AsRef<str>`), builders taking `impl Into<String>` accept it too, and it derefs to
+/// `str` for helpers taking `&str`.
+///
+/// # Example
+/// ```
+/// use extended_rust_sdk::models::MarketName;
+///
+/// let market: MarketName = "BTC-USD".parse().unwrap();
+/// assert_eq!(market.base(), "BTC");
+/// assert_eq!(market.quote(), "USD");
+/// assert!("BTCUSD".parse::<MarketName>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct MarketName(String);
+
+impl MarketName {
+    /// Validate a market name.
+    ///
+    /// Fails with `ExtendedError::InvalidParameter` unless the name is two non-empty
+    /// parts of uppercase ASCII letters and digits joined by a single `-`.
+    pub fn new(name: impl Into<String>) -> Result<Self> {
+        let name = name.into();
+        let valid_part = |part: &str| {
+            !part.is_empty() && part.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+        };
+        match name.split_once('-') {
+            Some((base, quote)) if valid_part(base) && valid_part(quote) => Ok(Self(name)),
+            _ => Err(ExtendedError::InvalidParameter(format!(
+                "invalid market name {:?}, expected BASE-QUOTE (e.g. \"BTC-USD\")",
+                name
+            ))),
+        }
+    }
+
+    /// Get the name as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Get the base asset (e.g. "BTC").
+    pub fn base(&self) -> &str {
+        self.0.split_once('-').map_or(&self.0, |(base, _)| base)
+    }
+
+    /// Get the quote asset (e.g. "USD").
+    pub fn quote(&self) -> &str {
+        self.0.split_once('-').map_or("", |(_, quote)| quote)
+    }
+}
+
+impl FromStr for MarketName {
+    type Err = ExtendedError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for MarketName {
+    type Error = ExtendedError;
+
+    fn try_from(name: String) -> Result<Self> {
+        Self::new(name)
+    }
+}
+
+impl TryFrom<&str> for MarketName {
+    type Error = ExtendedError;
+
+    fn try_from(name: &str) -> Result<Self> {
+        Self::new(name)
+    }
+}
+
+impl From<MarketName> for String {
+    fn from(name: MarketName) -> Self {
+        name.0
+    }
+}
+
+impl fmt::Display for MarketName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for MarketName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for MarketName {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for MarketName {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for MarketName {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for MarketName {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_market_names() {
+        for name in ["BTC-USD", "ETH-USD", "1000PEPE-USD", "BTC-ETH"] {
+            let market: MarketName = name.parse().unwrap();
+            assert_eq!(market.to_string(), name);
+            assert_eq!(market, name);
+        }
+        let market = MarketName::new("1000PEPE-USD").unwrap();
+        assert_eq!((market.base(), market.quote()), ("1000PEPE", "USD"));
+
+        let json = serde_json::to_string(&market).unwrap();
+        assert_eq!(json, r#""1000PEPE-USD""#);
+        assert_eq!(serde_json::from_str::<MarketName>(&json).unwrap(), market);
+    }
+
+    #[test]
+    fn test_invalid_market_names() {
+        let invalid = ["", "BTCUSD", "btc-usd", "BTC-", "-USD", "BTC-USD-PERP", "BTC-USD "];
+        for name in invalid {
+            let result = name.parse::<MarketName>();
+            assert!(
+                matches!(result, Err(ExtendedError::InvalidParameter(_))),
+                "{:?} parsed as {:?}",
+                name,
+                result
+            );
+        }
+        assert!(serde_json::from_str::<MarketName>(r#""BTCUSD""#).is_err());
+    }
+}
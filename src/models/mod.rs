@@ -4,16 +4,24 @@ mod balance;
 mod candle;
 mod common;
 mod market;
+mod market_name;
+mod nonce;
 mod order;
+mod orderbook;
 mod position;
 mod trade;
 mod withdrawal;
 
+pub mod indicators;
+
 pub use balance::*;
 pub use candle::*;
 pub use common::*;
 pub use market::*;
+pub use market_name::*;
+pub use nonce::*;
 pub use order::*;
+pub use orderbook::*;
 pub use position::*;
 pub use trade::*;
 pub use withdrawal::*;
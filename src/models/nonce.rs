@@ -0,0 +1,91 @@
+//! Nonce generation for signed orders.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Generator used by `OrderBuilder::build` when no nonce or generator is given.
+///
+/// Shared by the whole process, so orders built anywhere in it never share a nonce.
+pub static DEFAULT_NONCE_GENERATOR: TimestampNonceGenerator = TimestampNonceGenerator::new();
+
+pub(crate) fn now_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("System time before UNIX epoch")
+        .as_millis() as u64
+}
+
+/// Source of order nonces.
+///
+/// The exchange rejects an order whose nonce was already used, so every call must
+/// return a value not returned before. Implementations are shared between tasks placing
+/// orders concurrently, hence `Send + Sync`; plug one into a client with
+/// `TradingClient::with_nonce_generator` (e.g. to persist nonces across restarts).
+pub trait NonceGenerator: Send + Sync + fmt::Debug {
+    /// Get the next nonce.
+    fn next(&self) -> u64;
+}
+
+/// Nonces from the current time in milliseconds, bumped past the previous one if the
+/// clock hasn't moved (or went backwards), so they are strictly increasing.
+#[derive(Debug, Default)]
+pub struct TimestampNonceGenerator {
+    last: AtomicU64,
+}
+
+impl TimestampNonceGenerator {
+    /// Create a generator that starts from the current time.
+    pub const fn new() -> Self {
+        Self {
+            last: AtomicU64::new(0),
+        }
+    }
+}
+
+impl NonceGenerator for TimestampNonceGenerator {
+    fn next(&self) -> u64 {
+        let now = now_millis();
+        let previous = self
+            .last
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| Some(now.max(last + 1)))
+            .expect("closure always returns Some");
+        now.max(previous + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_rapid_nonces_are_unique_and_monotonic() {
+        let generator = TimestampNonceGenerator::new();
+        let nonces: Vec<u64> = (0..10_000).map(|_| generator.next()).collect();
+        assert!(nonces.windows(2).all(|w| w[0] < w[1]));
+        assert!(nonces[0] >= now_millis() - 1000);
+    }
+
+    #[test]
+    fn test_concurrent_nonces_are_unique() {
+        let generator = Arc::new(TimestampNonceGenerator::new());
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let generator = generator.clone();
+                std::thread::spawn(move || {
+                    (0..10_000).map(|_| generator.next()).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut all = HashSet::new();
+        for thread in threads {
+            let nonces = thread.join().unwrap();
+            // Each thread sees its own nonces increasing
+            assert!(nonces.windows(2).all(|w| w[0] < w[1]));
+            all.extend(nonces);
+        }
+        assert_eq!(all.len(), 80_000);
+    }
+}
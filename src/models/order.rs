@@ -3,11 +3,18 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
 
+use super::nonce::now_millis;
+use super::{Market, MarketConfig, NonceGenerator, DEFAULT_NONCE_GENERATOR};
+use crate::signing::{sign_order_with_params, OrderSigningParams, StarkSigner};
+
 /// Default taker fee rate (0.05% = 5 basis points).
 /// This is the standard fee tier. Use `get_fees()` to check your actual tier.
 /// Value: 0.0005 = 5 × 10^-4
 pub const DEFAULT_FEE_RATE: Decimal = Decimal::from_parts(5, 0, 0, false, 4);
 
+/// Default order expiry (1 hour) when none is set on the builder.
+const DEFAULT_EXPIRY_MILLIS: i64 = 3600 * 1000;
+
 /// Helper to deserialize string numbers as Decimal.
 fn decimal_from_string<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
 where
@@ -94,8 +101,13 @@ pub enum TimeInForce {
     /// Immediate or cancel.
     #[serde(rename = "IOC")]
     ImmediateOrCancel,
+    /// Fill or kill: filled in full immediately, or cancelled without filling.
+    #[serde(rename = "FOK")]
+    FillOrKill,
 }
 
+/// Limit orders default to good till time; `OrderBuilder::market` and the conditional
+/// builders switch to immediate-or-cancel.
 impl Default for TimeInForce {
     fn default() -> Self {
         Self::GoodTillTime
@@ -167,7 +179,10 @@ pub enum TriggerType {
 }
 
 /// Order details.
-#[derive(Debug, Clone, Deserialize)]
+///
+/// Serializes back to the API's JSON shape (camelCase, decimals as strings),
+/// so fetched orders can be cached or logged and read back later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
     /// Internal order ID (can be integer or string from API).
@@ -247,10 +262,27 @@ impl Order {
     pub fn is_filled(&self) -> bool {
         self.status == OrderStatus::Filled
     }
+
+    /// Calculate realized slippage in basis points versus an intended price.
+    ///
+    /// Positive values are adverse (paid more on a buy, received less on a sell),
+    /// negative values are price improvement. Returns `None` if the order has no
+    /// average fill price yet or the intended price is not positive.
+    pub fn realized_slippage_bps(&self, intended_price: Decimal) -> Option<Decimal> {
+        if intended_price <= Decimal::ZERO {
+            return None;
+        }
+        let avg = self.average_price?;
+        let diff = match self.side {
+            OrderSide::Buy => avg - intended_price,
+            OrderSide::Sell => intended_price - avg,
+        };
+        Some(diff / intended_price * Decimal::from(10_000))
+    }
 }
 
 /// Request to create a new order.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateOrderRequest {
     /// External order ID (derived from order hash or client-provided).
@@ -284,7 +316,10 @@ pub struct CreateOrderRequest {
     pub nonce: Decimal,
     /// Self-trade protection level.
     pub self_trade_protection_level: SelfTradeProtection,
-    /// Cancel ID for order replacement (optional).
+    /// External ID of the order this one replaces (optional).
+    ///
+    /// When set, the exchange cancels that order and places this one atomically: if the
+    /// cancel fails, this order is rejected too. Not part of the signed payload.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cancel_id: Option<String>,
     /// Stark settlement data containing signature, public key, and vault ID.
@@ -313,6 +348,47 @@ pub struct CreateOrderRequest {
     pub builder_id: Option<i32>,
 }
 
+impl CreateOrderRequest {
+    /// Round price and quantity onto the market's tick/step grid.
+    ///
+    /// Buy prices round down and sell prices up (never more aggressive than requested);
+    /// quantity rounds down. With [`AutoCorrect::RoundingAndMinSize`] a quantity that
+    /// would fall below the market's minimum order size is raised to it. Returns a
+    /// report of what changed.
+    pub fn round_to_market(&mut self, market: &Market, correction: AutoCorrect) -> RoundingReport {
+        let config = market.config();
+        let rounded_price = match self.side {
+            OrderSide::Buy => config.round_price_down(self.price),
+            OrderSide::Sell => config.round_price_up(self.price),
+        };
+        let mut rounded_quantity = config.round_qty_down(self.quantity);
+        if correction == AutoCorrect::RoundingAndMinSize {
+            rounded_quantity = rounded_quantity.max(config.round_qty_up(config.min_order_size));
+        }
+
+        let report = RoundingReport {
+            original_price: self.price,
+            rounded_price,
+            original_quantity: self.quantity,
+            rounded_quantity,
+        };
+        self.price = rounded_price;
+        self.quantity = rounded_quantity;
+        report
+    }
+}
+
+/// How `PrivateApi::create_order_auto_correct` may change a rejected order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AutoCorrect {
+    /// Only round price and quantity onto the market's tick/step grid.
+    #[default]
+    Rounding,
+    /// Also raise a quantity below the market's minimum order size to the minimum,
+    /// which makes the order larger than requested.
+    RoundingAndMinSize,
+}
+
 /// Conditional trigger configuration for stop/conditional orders.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -369,8 +445,9 @@ pub struct TpslTrigger {
     pub price: Decimal,
     /// Price type.
     pub price_type: OrderPriceType,
-    /// Settlement data for this trigger.
-    pub settlement: StarkSettlementModel,
+    /// Settlement data for this trigger (set when the order is signed).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settlement: Option<StarkSettlementModel>,
     /// Debugging amounts (optional).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub debugging_amounts: Option<StarkDebuggingOrderAmounts>,
@@ -412,6 +489,16 @@ pub struct StarkDebuggingOrderAmounts {
     pub synthetic_amount: Decimal,
 }
 
+/// Generate a random external order ID (a UUID v4).
+///
+/// Set one on an order before submitting it (see `OrderBuilder::auto_external_id`) and
+/// keep it: if `create_order` times out, `get_order_by_external_id` tells whether the
+/// first attempt landed, and if it didn't, the order can be re-submitted under the same
+/// ID without risk of placing it twice.
+pub fn generate_external_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
 /// Builder for creating order requests.
 #[derive(Debug, Clone)]
 pub struct OrderBuilder {
@@ -428,8 +515,14 @@ pub struct OrderBuilder {
     external_id: Option<String>,
     trigger_price: Option<Decimal>,
     trigger_type: Option<TriggerType>,
+    trigger: Option<ConditionalTrigger>,
+    take_profit: Option<TpslTrigger>,
+    stop_loss: Option<TpslTrigger>,
+    tpsl_type: Option<TpslType>,
     expiry_epoch_millis: Option<i64>,
     self_trade_protection: SelfTradeProtection,
+    cancel_id: Option<String>,
+    market_config: Option<MarketConfig>,
 }
 
 impl OrderBuilder {
@@ -464,11 +557,71 @@ impl OrderBuilder {
             external_id: None,
             trigger_price: None,
             trigger_type: None,
+            trigger: None,
+            take_profit: None,
+            stop_loss: None,
+            tpsl_type: None,
             expiry_epoch_millis: None,
             self_trade_protection: SelfTradeProtection::Disabled,
+            cancel_id: None,
+            market_config: None,
+        }
+    }
+
+    /// Create a new market order builder.
+    ///
+    /// The order is immediate-or-cancel. Market orders carry no limit price on the
+    /// exchange, but the Stark signature still needs one to compute the collateral
+    /// amount, so the worst acceptable fill price is required up front. Use
+    /// [`OrderBuilder::slippage_from`] to derive it from a reference price instead.
+    ///
+    /// # Arguments
+    /// * `market` - Market name (e.g., "BTC-USD")
+    /// * `side` - Buy or Sell
+    /// * `quantity` - Order quantity
+    /// * `slippage_price` - Worst acceptable fill price
+    pub fn market(
+        market: impl Into<String>,
+        side: OrderSide,
+        quantity: Decimal,
+        slippage_price: Decimal,
+    ) -> Self {
+        Self {
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::ImmediateOrCancel,
+            ..Self::limit(market, side, slippage_price, quantity, false, false)
         }
     }
 
+    /// Change the worst acceptable fill price for a market order.
+    ///
+    /// This replaces the `price` field used for Stark amount calculation.
+    pub fn slippage_price(mut self, price: Decimal) -> Self {
+        self.price = price;
+        self
+    }
+
+    /// Set the slippage price from a reference price and a maximum slippage
+    /// (e.g. `0.01` = 1%): above the reference for buys, below it for sells.
+    pub fn slippage_from(self, reference_price: Decimal, max_slippage: Decimal) -> Self {
+        let price = match self.side {
+            OrderSide::Buy => reference_price * (Decimal::ONE + max_slippage),
+            OrderSide::Sell => reference_price * (Decimal::ONE - max_slippage),
+        };
+        self.slippage_price(price)
+    }
+
+    /// Check the order against a market's constraints when it's built.
+    ///
+    /// The check runs in [`try_build`](Self::try_build), [`build_and_sign`](Self::build_and_sign)
+    /// and `TradingClient::place_order` on the final price and quantity, so later changes
+    /// (e.g. `slippage_price`) are covered too. [`build`](Self::build) doesn't check.
+    /// See `MarketConfig::validate_order` for the checks performed.
+    pub fn validate_against(mut self, config: &MarketConfig) -> Self {
+        self.market_config = Some(config.clone());
+        self
+    }
+
     /// Set time in force.
     pub fn time_in_force(mut self, tif: TimeInForce) -> Self {
         self.time_in_force = tif;
@@ -482,6 +635,10 @@ impl OrderBuilder {
     }
 
     /// Set post only flag.
+    ///
+    /// A post-only order that would cross the book is rejected with
+    /// `ExtendedError::OrderRejected` (check `is_post_only_crossed()`), distinct from
+    /// other validation errors, so it can be re-quoted straight away.
     pub fn post_only(mut self, post_only: bool) -> Self {
         self.post_only = post_only;
         self
@@ -493,7 +650,28 @@ impl OrderBuilder {
         self
     }
 
+    /// Set a freshly generated external ID (see [`generate_external_id`]).
+    ///
+    /// Read it back from the built request's `id` before submitting, so a timed-out
+    /// submission can be looked up and retried safely.
+    pub fn auto_external_id(self) -> Self {
+        self.external_id(generate_external_id())
+    }
+
+    /// Replace an existing order (cancel-replace).
+    ///
+    /// Takes the **external** ID of the order to replace (the `id` it was submitted
+    /// with, which is `PlacedOrderResponse::external_id`), not the exchange's internal ID.
+    /// The new order is only placed if the old one is cancelled.
+    pub fn replaces(mut self, external_id: impl Into<String>) -> Self {
+        self.cancel_id = Some(external_id.into());
+        self
+    }
+
     /// Set trigger price for conditional orders.
+    ///
+    /// This doesn't set a trigger direction, so the request's `trigger` stays empty;
+    /// prefer [`OrderBuilder::stop_market`] or [`OrderBuilder::stop_limit`].
     pub fn trigger(mut self, price: Decimal, trigger_type: TriggerType) -> Self {
         self.trigger_price = Some(price);
         self.trigger_type = Some(trigger_type);
@@ -501,6 +679,113 @@ impl OrderBuilder {
         self
     }
 
+    /// Make this a stop-market order: once the `trigger_type` price crosses
+    /// `trigger_price` in `direction`, it executes immediately as a market order.
+    ///
+    /// The order's price is the worst acceptable fill price and is what gets signed, so
+    /// set it with [`OrderBuilder::slippage_price`] relative to the trigger price.
+    pub fn stop_market(
+        self,
+        trigger_price: Decimal,
+        trigger_type: TriggerType,
+        direction: TriggerDirection,
+    ) -> Self {
+        Self {
+            time_in_force: TimeInForce::ImmediateOrCancel,
+            post_only: false,
+            ..self.conditional(trigger_price, trigger_type, direction, OrderPriceType::Market)
+        }
+    }
+
+    /// Make this a stop-limit order: once the `trigger_type` price crosses
+    /// `trigger_price` in `direction`, a limit order is placed at the order's price.
+    pub fn stop_limit(
+        self,
+        trigger_price: Decimal,
+        trigger_type: TriggerType,
+        direction: TriggerDirection,
+    ) -> Self {
+        self.conditional(trigger_price, trigger_type, direction, OrderPriceType::Limit)
+    }
+
+    fn conditional(
+        mut self,
+        trigger_price: Decimal,
+        trigger_type: TriggerType,
+        direction: TriggerDirection,
+        execution_price_type: OrderPriceType,
+    ) -> Self {
+        self.trigger_price = Some(trigger_price);
+        self.trigger_type = Some(trigger_type);
+        self.order_type = OrderType::Conditional;
+        self.trigger = Some(ConditionalTrigger {
+            trigger_price,
+            trigger_price_type: trigger_type,
+            direction,
+            execution_price_type,
+        });
+        self
+    }
+
+    /// Attach a take-profit to the order.
+    ///
+    /// When the last price reaches `trigger_price`, a closing order executes at
+    /// `execution_price` (the worst acceptable price for `OrderPriceType::Market`).
+    /// Use [`OrderBuilder::tpsl_trigger_price_type`] to trigger on mark or index instead.
+    /// The trigger's settlement is signed along with the order.
+    pub fn take_profit(
+        mut self,
+        trigger_price: Decimal,
+        execution_price: Decimal,
+        price_type: OrderPriceType,
+    ) -> Self {
+        self.take_profit = Some(Self::tpsl_trigger(trigger_price, execution_price, price_type));
+        self
+    }
+
+    /// Attach a stop-loss to the order. See [`OrderBuilder::take_profit`].
+    pub fn stop_loss(
+        mut self,
+        trigger_price: Decimal,
+        execution_price: Decimal,
+        price_type: OrderPriceType,
+    ) -> Self {
+        self.stop_loss = Some(Self::tpsl_trigger(trigger_price, execution_price, price_type));
+        self
+    }
+
+    /// Set whether the TP/SL applies to this order's size or the whole position.
+    ///
+    /// Defaults to `TpslType::Order` when a take-profit or stop-loss is attached.
+    pub fn tpsl_type(mut self, tpsl_type: TpslType) -> Self {
+        self.tpsl_type = Some(tpsl_type);
+        self
+    }
+
+    /// Set the price the attached take-profit and stop-loss trigger on
+    /// (default `TriggerType::Last`). Call after attaching them.
+    pub fn tpsl_trigger_price_type(mut self, trigger_type: TriggerType) -> Self {
+        for trigger in [&mut self.take_profit, &mut self.stop_loss].into_iter().flatten() {
+            trigger.trigger_price_type = trigger_type;
+        }
+        self
+    }
+
+    fn tpsl_trigger(
+        trigger_price: Decimal,
+        execution_price: Decimal,
+        price_type: OrderPriceType,
+    ) -> TpslTrigger {
+        TpslTrigger {
+            trigger_price,
+            trigger_price_type: TriggerType::Last,
+            price: execution_price,
+            price_type,
+            settlement: None,
+            debugging_amounts: None,
+        }
+    }
+
     /// Set expiry time.
     pub fn expiry(mut self, expiry_millis: i64) -> Self {
         self.expiry_epoch_millis = Some(expiry_millis);
@@ -514,45 +799,117 @@ impl OrderBuilder {
     }
 
     /// Override the fee rate (default is DEFAULT_FEE_RATE = 0.0005).
-    /// Use your tier's taker rate from `get_fees()` if different.
+    /// Use your tier's rate from `PrivateApi::market_fee` if different
+    /// (`TradingClient::place_order` does this for you).
     pub fn fee(mut self, fee: Decimal) -> Self {
         self.fee = fee;
         self
     }
 
-    /// Override the nonce (default is auto-generated from current timestamp).
+    /// Override the nonce (default is taken from [`DEFAULT_NONCE_GENERATOR`]).
+    ///
+    /// Explicit nonces are not checked against generated ones, so mixing the two is up to
+    /// the caller to keep unique.
     pub fn nonce(mut self, nonce: u64) -> Self {
         self.nonce = Some(nonce);
         self
     }
 
+    /// Build, then round price and quantity to the market's tick/step size with
+    /// [`CreateOrderRequest::round_to_market`].
+    ///
+    /// Prices are rounded in the direction that never worsens the order for the user
+    /// (down for buys, up for sells); quantities are always rounded down so the order
+    /// never exceeds the requested size. The returned report lists what changed, so a
+    /// UI can tell the user e.g. "price 50000.7 was adjusted to 50000.5".
+    pub fn build_rounded_reported(self, market: &Market) -> (CreateOrderRequest, RoundingReport) {
+        let mut order = self.build();
+        let report = order.round_to_market(market, AutoCorrect::Rounding);
+        (order, report)
+    }
+
+    /// Copy this builder `count` times, giving each copy its own nonce.
+    ///
+    /// Use this when constructing a large batch (e.g. every level of a grid) in a tight
+    /// loop: nonces are guaranteed distinct even when many are taken within the same
+    /// millisecond, and copies without an explicit expiry share one default expiry so
+    /// the whole batch lapses together. Adjust price/quantity on each copy before
+    /// building. A nonce already set via `.nonce()` is overwritten.
+    pub fn build_many(&self, count: usize) -> Vec<OrderBuilder> {
+        let expiry = self
+            .expiry_epoch_millis
+            .unwrap_or_else(|| now_millis() as i64 + DEFAULT_EXPIRY_MILLIS);
+        (0..count)
+            .map(|_| self.clone().nonce(DEFAULT_NONCE_GENERATOR.next()).expiry(expiry))
+            .collect()
+    }
+
     /// Build the order request (without settlement - must be signed separately).
     ///
-    /// Nonce is auto-generated from current timestamp if not set via `.nonce()`.
+    /// Nonce is taken from [`DEFAULT_NONCE_GENERATOR`] if not set via `.nonce()`; generated
+    /// nonces are strictly increasing across the process, so two orders built within the
+    /// same millisecond never collide.
     /// Fee defaults to DEFAULT_FEE_RATE (0.0005) if not set via `.fee()`.
     /// Expiry defaults to 1 hour from now if not set via `.expiry()`.
     /// The `id` field is set to the nonce as string (will be replaced with order hash after signing).
     pub fn build(self) -> CreateOrderRequest {
-        let nonce = self.nonce.unwrap_or_else(|| {
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .expect("System time before UNIX epoch")
-                .as_millis() as u64
-        });
+        self.build_with(&DEFAULT_NONCE_GENERATOR)
+    }
+
+    /// Build the order request, first checking it against the market constraints set with
+    /// [`validate_against`](Self::validate_against).
+    ///
+    /// Fails with `ExtendedError::OrderValidation` describing the first violation.
+    /// Otherwise the same as [`build`](Self::build).
+    pub fn try_build(self) -> crate::error::Result<CreateOrderRequest> {
+        self.try_build_with(&DEFAULT_NONCE_GENERATOR)
+    }
+
+    /// Like [`try_build`](Self::try_build), taking the nonce from `nonces` if not set via
+    /// `.nonce()`.
+    pub fn try_build_with(
+        self,
+        nonces: &dyn NonceGenerator,
+    ) -> crate::error::Result<CreateOrderRequest> {
+        if let Some(config) = &self.market_config {
+            config.validate_order(self.price, self.quantity, self.order_type)?;
+        }
+        Ok(self.build_with(nonces))
+    }
+
+    /// Build the order request and sign it with `sign_order_with_params`.
+    ///
+    /// Prefer this to building and signing separately: the signed amounts, nonce and
+    /// expiry are guaranteed to be the ones in the request, and the same `params`
+    /// (e.g. from `Market::synthetic_asset_id` and friends) are used for every leg.
+    /// Market constraints set with [`validate_against`](Self::validate_against) are
+    /// checked first.
+    pub fn build_and_sign(
+        self,
+        signer: &StarkSigner,
+        params: &OrderSigningParams,
+    ) -> crate::error::Result<CreateOrderRequest> {
+        sign_order_with_params(self.try_build()?, signer, params)
+    }
+
+    /// Build the order request, taking the nonce from `nonces` if not set via `.nonce()`.
+    ///
+    /// Otherwise the same as [`build`](Self::build).
+    pub fn build_with(self, nonces: &dyn NonceGenerator) -> CreateOrderRequest {
+        let nonce = self.nonce.unwrap_or_else(|| nonces.next());
 
         // Default expiry is 1 hour from now
-        let expiry = self.expiry_epoch_millis.unwrap_or_else(|| {
-            let now = std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .expect("System time before UNIX epoch")
-                .as_millis() as i64;
-            now + 3600 * 1000 // 1 hour in milliseconds
-        });
+        let expiry = self
+            .expiry_epoch_millis
+            .unwrap_or_else(|| now_millis() as i64 + DEFAULT_EXPIRY_MILLIS);
 
         // Use external_id if provided, otherwise use nonce as temporary ID
         // (will be replaced with order hash after signing)
         let id = self.external_id.clone().unwrap_or_else(|| nonce.to_string());
 
+        let has_tpsl = self.take_profit.is_some() || self.stop_loss.is_some();
+        let tp_sl_type = self.tpsl_type.or(has_tpsl.then_some(TpslType::Order));
+
         CreateOrderRequest {
             id,
             market: self.market,
@@ -567,12 +924,12 @@ impl OrderBuilder {
             fee: self.fee,
             nonce: Decimal::from(nonce),
             self_trade_protection_level: self.self_trade_protection,
-            cancel_id: None,
+            cancel_id: self.cancel_id,
             settlement: None,
-            trigger: None,
-            tp_sl_type: None,
-            take_profit: None,
-            stop_loss: None,
+            trigger: self.trigger,
+            tp_sl_type,
+            take_profit: self.take_profit,
+            stop_loss: self.stop_loss,
             debugging_amounts: None,
             builder_fee: None,
             builder_id: None,
@@ -580,8 +937,61 @@ impl OrderBuilder {
     }
 }
 
+/// Report of the adjustments made when rounding an order to market constraints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundingReport {
+    /// Price as requested.
+    pub original_price: Decimal,
+    /// Price after rounding to the tick size.
+    pub rounded_price: Decimal,
+    /// Quantity as requested.
+    pub original_quantity: Decimal,
+    /// Quantity after rounding to the step size.
+    pub rounded_quantity: Decimal,
+}
+
+impl RoundingReport {
+    /// Check if the price was adjusted.
+    pub fn price_changed(&self) -> bool {
+        self.original_price != self.rounded_price
+    }
+
+    /// Check if the quantity was adjusted.
+    pub fn quantity_changed(&self) -> bool {
+        self.original_quantity != self.rounded_quantity
+    }
+
+    /// Check if nothing was adjusted.
+    pub fn is_unchanged(&self) -> bool {
+        !self.price_changed() && !self.quantity_changed()
+    }
+}
+
+impl std::fmt::Display for RoundingReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut changes = Vec::new();
+        if self.price_changed() {
+            changes.push(format!(
+                "price {} was adjusted to {}",
+                self.original_price, self.rounded_price
+            ));
+        }
+        if self.quantity_changed() {
+            changes.push(format!(
+                "quantity {} was adjusted to {}",
+                self.original_quantity, self.rounded_quantity
+            ));
+        }
+        if changes.is_empty() {
+            write!(f, "no adjustments")
+        } else {
+            write!(f, "{}", changes.join(", "))
+        }
+    }
+}
+
 /// Parameters for cancelling orders.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MassCancelParams {
     /// Market to cancel orders for (optional, all markets if not specified).
@@ -590,6 +1000,19 @@ pub struct MassCancelParams {
     /// Side to cancel (optional, both sides if not specified).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub side: Option<OrderSide>,
+    /// Specific order IDs to cancel (optional).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_ids: Option<Vec<String>>,
+}
+
+impl MassCancelParams {
+    /// Cancel a specific set of orders by ID.
+    pub fn for_orders(order_ids: Vec<String>) -> Self {
+        Self {
+            order_ids: Some(order_ids),
+            ..Default::default()
+        }
+    }
 }
 
 /// Response from mass cancel operation.
@@ -614,6 +1037,17 @@ pub struct PlacedOrderResponse {
 }
 
 /// Parameters for fetching orders.
+///
+/// # Example
+/// ```
+/// use extended_rust_sdk::models::{GetOrdersParams, OrderStatus};
+///
+/// let params = GetOrdersParams::new()
+///     .with_market("BTC-USD")
+///     .with_status(OrderStatus::Open)
+///     .with_limit(50);
+/// assert_eq!(params.market.as_deref(), Some("BTC-USD"));
+/// ```
 #[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetOrdersParams {
@@ -626,6 +1060,12 @@ pub struct GetOrdersParams {
     /// Filter by status.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<OrderStatus>,
+    /// Start timestamp (Unix ms).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// End timestamp (Unix ms).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
     /// Pagination cursor.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<i64>,
@@ -633,3 +1073,263 @@ pub struct GetOrdersParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
 }
+
+impl GetOrdersParams {
+    /// Create empty parameters (no filters).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filter by market.
+    pub fn with_market(mut self, market: impl Into<String>) -> Self {
+        self.market = Some(market.into());
+        self
+    }
+
+    /// Filter by side.
+    pub fn with_side(mut self, side: OrderSide) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    /// Filter by status.
+    pub fn with_status(mut self, status: OrderStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Set the time range (Unix ms).
+    pub fn with_range(mut self, start: i64, end: i64) -> Self {
+        self.start_time = Some(start);
+        self.end_time = Some(end);
+        self
+    }
+
+    /// Set the pagination cursor.
+    pub fn with_cursor(mut self, cursor: i64) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Set the maximum number of results.
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_market_order_builder() {
+        let order =
+            OrderBuilder::market("BTC-USD", OrderSide::Buy, dec!(0.5), dec!(101000)).build();
+        assert_eq!(order.order_type, OrderType::Market);
+        assert_eq!(order.time_in_force, TimeInForce::ImmediateOrCancel);
+        assert_eq!(order.price, dec!(101000));
+        assert_eq!(order.quantity, dec!(0.5));
+        assert!(!order.post_only);
+
+        let sell = OrderBuilder::market("BTC-USD", OrderSide::Sell, dec!(1), dec!(100000))
+            .slippage_from(dec!(100000), dec!(0.01))
+            .build();
+        assert_eq!(sell.price, dec!(99000));
+    }
+
+    #[test]
+    fn test_validate_against() {
+        let config: MarketConfig = serde_json::from_value(serde_json::json!({
+            "minOrderSize": "0.001",
+            "minOrderSizeChange": "0.001",
+            "minPriceChange": "0.1",
+            "maxMarketOrderValue": "1000000",
+            "maxLimitOrderValue": "5000000",
+            "maxPositionValue": "10000000",
+            "maxLeverage": "50",
+            "maxNumOrders": "200",
+            "limitPriceCap": "0.05",
+            "limitPriceFloor": "0.05"
+        }))
+        .unwrap();
+
+        let buy = |price| {
+            OrderBuilder::limit("BTC-USD", OrderSide::Buy, price, dec!(0.01), false, false)
+                .validate_against(&config)
+        };
+        assert!(buy(dec!(93050.2)).try_build().is_ok());
+
+        let err = buy(dec!(93050.21)).try_build().unwrap_err();
+        assert!(err.to_string().contains("not aligned to tick 0.1"));
+
+        // The final price is checked, not the one when the constraints were set
+        let market = OrderBuilder::market("BTC-USD", OrderSide::Buy, dec!(0.01), dec!(93050.2))
+            .validate_against(&config)
+            .slippage_price(dec!(93050.25));
+        let err = market.clone().try_build().unwrap_err();
+        assert!(err.to_string().contains("not aligned to tick 0.1"));
+        assert!(market.slippage_price(dec!(93050.3)).try_build().is_ok());
+
+        for price in [Decimal::ZERO, dec!(-93050.2)] {
+            let err = buy(price).try_build().unwrap_err();
+            assert!(err.to_string().contains("must be positive"), "{}", err);
+        }
+        // Without constraints nothing is checked
+        assert!(OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(1.23), dec!(0.01), false, false)
+            .try_build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_order_serde_round_trip() {
+        let payload = serde_json::json!({
+            "id": 1775511783722512384u64,
+            "accountId": 3017,
+            "externalId": "ext-7",
+            "market": "BTC-USD",
+            "type": "LIMIT",
+            "side": "BUY",
+            "status": "PARTIALLY_FILLED",
+            "price": "95000.5",
+            "qty": "0.010",
+            "filledQty": "0.004",
+            "averagePrice": "95000.5",
+            "timeInForce": "GTT",
+            "reduceOnly": false,
+            "postOnly": true,
+            "createdTime": 1701563440000i64,
+            "updatedTime": 1701563440500i64,
+            "expireTime": 1702168240000i64,
+            "payedFee": "0.019"
+        });
+
+        let order: Order = serde_json::from_value(payload).unwrap();
+        let json = serde_json::to_value(&order).unwrap();
+
+        // Integer ids come back as strings, decimals keep their scale
+        assert_eq!(json["id"], "1775511783722512384");
+        assert_eq!(json["type"], "LIMIT");
+        assert_eq!(json["qty"], "0.010");
+        assert_eq!(json["filledQty"], "0.004");
+        assert_eq!(json["payedFee"], "0.019");
+        assert_eq!(json["createdTime"], 1701563440000i64);
+
+        let again: Order = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&again).unwrap(), json);
+        assert_eq!(again.id, order.id);
+        assert_eq!(again.status, OrderStatus::PartiallyFilled);
+        assert_eq!(again.cancelled_quantity, None);
+    }
+
+    #[test]
+    fn test_fill_or_kill_round_trip() {
+        let tif: TimeInForce = serde_json::from_str(r#""FOK""#).unwrap();
+        assert_eq!(tif, TimeInForce::FillOrKill);
+        assert_eq!(serde_json::to_string(&tif).unwrap(), r#""FOK""#);
+
+        let order =
+            OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000), dec!(0.01), false, false)
+                .time_in_force(TimeInForce::FillOrKill)
+                .build();
+        let json = serde_json::to_value(&order).unwrap();
+        assert_eq!(json["timeInForce"], "FOK");
+        let again: CreateOrderRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(again.time_in_force, TimeInForce::FillOrKill);
+    }
+
+    #[test]
+    fn test_auto_external_id() {
+        let ids: std::collections::HashSet<String> =
+            (0..1000).map(|_| generate_external_id()).collect();
+        assert_eq!(ids.len(), 1000);
+
+        let builder =
+            OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000), dec!(0.01), false, false)
+                .auto_external_id();
+        let order = builder.clone().build();
+        assert_eq!(order.id.len(), 36);
+        assert_ne!(order.id, order.nonce.to_string());
+        // Rebuilding for a retry keeps the ID
+        assert_eq!(builder.build().id, order.id);
+    }
+
+    #[test]
+    fn test_replaces_serializes_cancel_id() {
+        let order =
+            OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000), dec!(0.01), false, false)
+                .replaces("ext-old")
+                .build();
+        let json = serde_json::to_value(&order).unwrap();
+        assert_eq!(json["cancelId"], "ext-old");
+    }
+
+    #[test]
+    fn test_stop_orders_populate_trigger() {
+        let stop = OrderBuilder::market("BTC-USD", OrderSide::Sell, dec!(0.1), dec!(90000))
+            .stop_market(dec!(90000), TriggerType::Mark, TriggerDirection::Down)
+            .slippage_from(dec!(90000), dec!(0.01))
+            .build();
+        assert_eq!(stop.order_type, OrderType::Conditional);
+        assert_eq!(stop.time_in_force, TimeInForce::ImmediateOrCancel);
+        let json = serde_json::to_value(&stop).unwrap();
+        assert_eq!(json["type"], "CONDITIONAL");
+        assert_eq!(json["price"], "89100.00");
+        assert_eq!(
+            json["trigger"],
+            serde_json::json!({
+                "triggerPrice": "90000",
+                "triggerPriceType": "MARK",
+                "direction": "DOWN",
+                "executionPriceType": "MARKET"
+            })
+        );
+
+        let stop_limit =
+            OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(101000), dec!(0.1), false, false)
+                .stop_limit(dec!(100500), TriggerType::Last, TriggerDirection::Up)
+                .build();
+        let json = serde_json::to_value(&stop_limit).unwrap();
+        assert_eq!(json["trigger"]["direction"], "UP");
+        assert_eq!(json["trigger"]["executionPriceType"], "LIMIT");
+        assert_eq!(json["price"], "101000");
+    }
+
+    #[test]
+    fn test_generated_nonces_are_distinct() {
+        let builder =
+            OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000), dec!(0.01), true, false);
+        let orders: Vec<_> = (0..1000).map(|_| builder.clone().build()).collect();
+        let nonces: std::collections::HashSet<_> = orders.iter().map(|o| o.nonce).collect();
+        assert_eq!(nonces.len(), orders.len());
+
+        let batch: Vec<_> = builder.build_many(500).into_iter().map(|b| b.build()).collect();
+        assert!(batch.windows(2).all(|w| w[0].nonce < w[1].nonce));
+        assert!(batch.iter().all(|o| o.expiry_epoch_millis == batch[0].expiry_epoch_millis));
+        assert!(batch[0].nonce > orders.last().unwrap().nonce);
+    }
+
+    #[test]
+    fn test_get_orders_params_builder() {
+        let params = GetOrdersParams::new()
+            .with_market("BTC-USD")
+            .with_side(OrderSide::Sell)
+            .with_status(OrderStatus::Open)
+            .with_cursor(1200)
+            .with_limit(50);
+        assert_eq!(
+            serde_urlencoded::to_string(&params).unwrap(),
+            "market=BTC-USD&side=SELL&status=OPEN&cursor=1200&limit=50"
+        );
+
+        // The range stays on every page as the cursor advances
+        let page = params.with_range(1_700_000_000_000, 1_700_086_400_000).with_cursor(1300);
+        assert_eq!(
+            serde_urlencoded::to_string(&page).unwrap(),
+            "market=BTC-USD&side=SELL&status=OPEN&startTime=1700000000000\
+             &endTime=1700086400000&cursor=1300&limit=50"
+        );
+        assert_eq!(serde_urlencoded::to_string(GetOrdersParams::new()).unwrap(), "");
+    }
+}
@@ -0,0 +1,220 @@
+//! Locally maintained order book built from a snapshot plus incremental updates.
+
+use rust_decimal::Decimal;
+
+use super::{OrderBook, PriceQuantity};
+use crate::error::{ExtendedError, Result};
+
+/// Incremental order book update.
+///
+/// Each level carries the new total quantity at that price; a zero quantity removes
+/// the level.
+#[derive(Debug, Clone)]
+pub struct OrderBookDelta {
+    /// Sequence number of the update.
+    pub sequence: i64,
+    /// Changed bid levels.
+    pub bids: Vec<PriceQuantity>,
+    /// Changed ask levels.
+    pub asks: Vec<PriceQuantity>,
+}
+
+/// Order book kept up to date by applying deltas on top of a snapshot.
+///
+/// Bids are kept sorted by price descending and asks ascending. Updates must arrive
+/// with consecutive sequence numbers; a gap is reported as
+/// `ExtendedError::SequenceGap`, after which the book should be resynced from a fresh
+/// snapshot. Updates older than the current sequence are ignored.
+#[derive(Debug, Clone)]
+pub struct LocalOrderBook {
+    market: String,
+    bids: Vec<PriceQuantity>,
+    asks: Vec<PriceQuantity>,
+    sequence: Option<i64>,
+}
+
+impl LocalOrderBook {
+    /// Create a local book from a snapshot.
+    pub fn new(snapshot: OrderBook) -> Self {
+        let mut book = Self {
+            market: snapshot.market,
+            bids: Vec::new(),
+            asks: Vec::new(),
+            sequence: snapshot.sequence,
+        };
+        for level in snapshot.bids {
+            set_level(&mut book.bids, level, Side::Bid);
+        }
+        for level in snapshot.asks {
+            set_level(&mut book.asks, level, Side::Ask);
+        }
+        book
+    }
+
+    /// Apply an incremental update.
+    ///
+    /// Returns `ExtendedError::SequenceGap` (leaving the book untouched) if the update
+    /// does not directly follow the last applied sequence. Stale updates are ignored.
+    pub fn apply_update(&mut self, update: OrderBookDelta) -> Result<()> {
+        if let Some(current) = self.sequence {
+            if update.sequence <= current {
+                return Ok(());
+            }
+            if update.sequence != current + 1 {
+                return Err(ExtendedError::SequenceGap {
+                    expected: current + 1,
+                    received: update.sequence,
+                });
+            }
+        }
+
+        for level in update.bids {
+            set_level(&mut self.bids, level, Side::Bid);
+        }
+        for level in update.asks {
+            set_level(&mut self.asks, level, Side::Ask);
+        }
+        self.sequence = Some(update.sequence);
+        Ok(())
+    }
+
+    /// Get the market name.
+    pub fn market(&self) -> &str {
+        &self.market
+    }
+
+    /// Get the last applied sequence number.
+    pub fn sequence(&self) -> Option<i64> {
+        self.sequence
+    }
+
+    /// Get bid levels, sorted by price descending.
+    pub fn bids(&self) -> &[PriceQuantity] {
+        &self.bids
+    }
+
+    /// Get ask levels, sorted by price ascending.
+    pub fn asks(&self) -> &[PriceQuantity] {
+        &self.asks
+    }
+
+    /// Get the best bid price.
+    pub fn best_bid(&self) -> Option<&PriceQuantity> {
+        self.bids.first()
+    }
+
+    /// Get the best ask price.
+    pub fn best_ask(&self) -> Option<&PriceQuantity> {
+        self.asks.first()
+    }
+
+    /// Get the mid price.
+    pub fn mid_price(&self) -> Option<Decimal> {
+        match (self.best_bid(), self.best_ask()) {
+            (Some(bid), Some(ask)) => Some((bid.price + ask.price) / Decimal::from(2)),
+            _ => None,
+        }
+    }
+
+    /// Get the spread.
+    pub fn spread(&self) -> Option<Decimal> {
+        match (self.best_bid(), self.best_ask()) {
+            (Some(bid), Some(ask)) => Some(ask.price - bid.price),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Side {
+    Bid,
+    Ask,
+}
+
+/// Insert, replace or remove a level, keeping the side sorted best-first.
+fn set_level(levels: &mut Vec<PriceQuantity>, level: PriceQuantity, side: Side) {
+    let position = levels.binary_search_by(|existing| match side {
+        Side::Bid => level.price.cmp(&existing.price),
+        Side::Ask => existing.price.cmp(&level.price),
+    });
+    match (position, level.quantity.is_zero()) {
+        (Ok(i), true) => {
+            levels.remove(i);
+        }
+        (Ok(i), false) => levels[i] = level,
+        (Err(_), true) => {}
+        (Err(i), false) => levels.insert(i, level),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn pq(price: Decimal, quantity: Decimal) -> PriceQuantity {
+        PriceQuantity { price, quantity }
+    }
+
+    fn snapshot() -> LocalOrderBook {
+        LocalOrderBook::new(OrderBook {
+            market: "BTC-USD".to_string(),
+            bids: vec![pq(dec!(99), dec!(1)), pq(dec!(100), dec!(2))],
+            asks: vec![pq(dec!(102), dec!(1)), pq(dec!(101), dec!(3))],
+            timestamp: 0,
+            sequence: Some(10),
+        })
+    }
+
+    fn delta(sequence: i64, bids: Vec<PriceQuantity>, asks: Vec<PriceQuantity>) -> OrderBookDelta {
+        OrderBookDelta { sequence, bids, asks }
+    }
+
+    #[test]
+    fn test_snapshot_is_sorted() {
+        let book = snapshot();
+        assert_eq!(book.best_bid().unwrap().price, dec!(100));
+        assert_eq!(book.best_ask().unwrap().price, dec!(101));
+        assert_eq!(book.mid_price(), Some(dec!(100.5)));
+        assert_eq!(book.spread(), Some(dec!(1)));
+    }
+
+    #[test]
+    fn test_apply_updates_in_order() {
+        let mut book = snapshot();
+        let bids = vec![pq(dec!(100.5), dec!(4))];
+        let asks = vec![pq(dec!(101), dec!(0))];
+        book.apply_update(delta(11, bids, asks)).unwrap();
+        assert_eq!(book.best_bid(), Some(&pq(dec!(100.5), dec!(4))));
+        assert_eq!(book.best_ask(), Some(&pq(dec!(102), dec!(1))));
+
+        book.apply_update(delta(12, vec![pq(dec!(99), dec!(5))], vec![])).unwrap();
+        let bid_prices: Vec<Decimal> = book.bids().iter().map(|l| l.price).collect();
+        assert_eq!(bid_prices, vec![dec!(100.5), dec!(100), dec!(99)]);
+        assert_eq!(book.bids()[2].quantity, dec!(5));
+        assert_eq!(book.sequence(), Some(12));
+    }
+
+    #[test]
+    fn test_out_of_order_update_is_ignored() {
+        let mut book = snapshot();
+        book.apply_update(delta(11, vec![], vec![])).unwrap();
+        book.apply_update(delta(10, vec![pq(dec!(150), dec!(1))], vec![])).unwrap();
+        assert_eq!(book.best_bid().unwrap().price, dec!(100));
+        assert_eq!(book.sequence(), Some(11));
+    }
+
+    #[test]
+    fn test_sequence_gap_is_reported() {
+        let mut book = snapshot();
+        let err = book
+            .apply_update(delta(13, vec![pq(dec!(150), dec!(1))], vec![]))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ExtendedError::SequenceGap { expected: 11, received: 13 }
+        ));
+        assert_eq!(book.best_bid().unwrap().price, dec!(100));
+        assert_eq!(book.sequence(), Some(10));
+    }
+}
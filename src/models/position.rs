@@ -1,8 +1,14 @@
 //! Position-related models.
 
+use std::collections::HashMap;
+use std::time::Duration;
+
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
 
+use super::{Balance, OrderSide};
+
 /// Position side (Long or Short).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -13,6 +19,51 @@ pub enum PositionSide {
     Short,
 }
 
+impl PositionSide {
+    /// Get the order side that opens or increases a position on this side
+    /// (Long -> Buy, Short -> Sell). Same as `OrderSide::from(side)`.
+    pub fn opening_order_side(&self) -> OrderSide {
+        OrderSide::from(*self)
+    }
+
+    /// Get the order side that reduces or closes a position on this side
+    /// (Long -> Sell, Short -> Buy).
+    pub fn closing_order_side(&self) -> OrderSide {
+        self.opening_order_side().opposite()
+    }
+
+    /// Get the opposite position side.
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::Long => Self::Short,
+            Self::Short => Self::Long,
+        }
+    }
+}
+
+/// Maps a position side to the order side in the same direction (Long -> Buy, Short -> Sell).
+///
+/// This is the side that *opens* the position. To close a position, use
+/// `PositionSide::closing_order_side()`.
+impl From<PositionSide> for OrderSide {
+    fn from(side: PositionSide) -> Self {
+        match side {
+            PositionSide::Long => OrderSide::Buy,
+            PositionSide::Short => OrderSide::Sell,
+        }
+    }
+}
+
+/// Maps an order side to the position side it opens (Buy -> Long, Sell -> Short).
+impl From<OrderSide> for PositionSide {
+    fn from(side: OrderSide) -> Self {
+        match side {
+            OrderSide::Buy => PositionSide::Long,
+            OrderSide::Sell => PositionSide::Short,
+        }
+    }
+}
+
 /// Helper to deserialize string numbers as Decimal.
 fn decimal_from_string<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
 where
@@ -95,6 +146,103 @@ impl Position {
         self.side == PositionSide::Short
     }
 
+    /// Get the order side that closes this position.
+    pub fn closing_order_side(&self) -> OrderSide {
+        self.side.closing_order_side()
+    }
+
+    /// Get the position notional value (unsigned).
+    ///
+    /// Uses the API-provided `value` when present, otherwise `|size| * mark_price`.
+    pub fn notional(&self) -> Decimal {
+        self.value
+            .map(|v| v.abs())
+            .unwrap_or_else(|| self.size.abs() * self.mark_price)
+    }
+
+    /// Get the position notional signed by direction (positive long, negative short).
+    pub fn signed_notional(&self) -> Decimal {
+        match self.side {
+            PositionSide::Long => self.notional(),
+            PositionSide::Short => -self.notional(),
+        }
+    }
+
+    /// Get the funding this position pays per hour at the given hourly rate.
+    ///
+    /// Positive when the position pays (longs at a positive rate, shorts at a
+    /// negative one), negative when it receives.
+    pub fn hourly_funding_cost(&self, funding_rate: Decimal) -> Decimal {
+        self.signed_notional() * funding_rate
+    }
+
+    /// Project the funding this position pays over `hours` at the given hourly rate.
+    ///
+    /// Uses the same sign as `hourly_funding_cost`: positive when the position pays,
+    /// negative when it receives. That's the opposite of `FundingPayment::payment`, so
+    /// compare it against the negated `total_funding` of past payments.
+    pub fn projected_funding_cost(&self, funding_rate: Decimal, hours: u32) -> Decimal {
+        self.hourly_funding_cost(funding_rate) * Decimal::from(hours)
+    }
+
+    /// Estimate how long funding at the given hourly rate takes to erode the
+    /// unrealized profit.
+    ///
+    /// Returns `None` when the position receives (or pays no) funding, since
+    /// holding doesn't cost anything. Returns `Duration::ZERO` for a funding-paying
+    /// position that is already at or below break-even: every hour held deepens the loss.
+    pub fn funding_break_even(&self, funding_rate: Decimal) -> Option<Duration> {
+        let cost = self.hourly_funding_cost(funding_rate);
+        if cost <= Decimal::ZERO {
+            return None;
+        }
+        if self.unrealized_pnl <= Decimal::ZERO {
+            return Some(Duration::ZERO);
+        }
+        let hours = self.unrealized_pnl.checked_div(cost)?;
+        let secs = hours.checked_mul(Decimal::from(3600))?.round().to_u64()?;
+        Some(Duration::from_secs(secs))
+    }
+
+    /// Estimate the liquidation price with the isolated-margin formula.
+    ///
+    /// The position's margin is `entry_price / leverage` per unit, and it's liquidated
+    /// once losses leave only the maintenance margin at the liquidation price:
+    /// `entry * (1 - 1/leverage) / (1 - mmr)` for longs and
+    /// `entry * (1 + 1/leverage) / (1 + mmr)` for shorts. Cross-margined accounts
+    /// share margin between positions, so treat this as an approximation there.
+    ///
+    /// Returns `None` for an empty position, a non-positive leverage, or a rate that
+    /// leaves no liquidation price (a long with `mmr >= 1`).
+    ///
+    /// # Arguments
+    /// * `maintenance_margin_rate` - Maintenance margin as a fraction of notional, e.g.
+    ///   derived from the market's `risk_factor_config` tier for this position's size
+    pub fn estimate_liquidation_price(&self, maintenance_margin_rate: Decimal) -> Option<Decimal> {
+        if self.size.is_zero() || self.leverage <= Decimal::ZERO {
+            return None;
+        }
+        let margin_rate = Decimal::ONE / self.leverage;
+        let price = match self.side {
+            PositionSide::Long => (self.entry_price * (Decimal::ONE - margin_rate))
+                .checked_div(Decimal::ONE - maintenance_margin_rate)?,
+            PositionSide::Short => (self.entry_price * (Decimal::ONE + margin_rate))
+                .checked_div(Decimal::ONE + maintenance_margin_rate)?,
+        };
+        (price > Decimal::ZERO).then_some(price)
+    }
+
+    /// Get the API's liquidation price, or estimate it when the API omits it.
+    ///
+    /// See `estimate_liquidation_price`.
+    pub fn liquidation_price_or_estimate(
+        &self,
+        maintenance_margin_rate: Decimal,
+    ) -> Option<Decimal> {
+        self.liquidation_price
+            .or_else(|| self.estimate_liquidation_price(maintenance_margin_rate))
+    }
+
     /// Get margin, defaulting to zero if not present.
     pub fn get_margin(&self) -> Decimal {
         self.margin.unwrap_or(Decimal::ZERO)
@@ -121,6 +269,146 @@ impl Position {
     }
 }
 
+/// A position's share of the account's initial margin.
+#[derive(Debug, Clone)]
+pub struct PositionMargin {
+    /// Market name.
+    pub market: String,
+    /// Position side.
+    pub side: PositionSide,
+    /// Margin allocated to the position.
+    pub margin: Decimal,
+    /// Share of the account's total initial margin (0.25 = 25%).
+    pub share: Decimal,
+}
+
+/// Breakdown of account initial margin by position.
+#[derive(Debug, Clone)]
+pub struct MarginBreakdown {
+    /// Per-position margin, largest first.
+    pub positions: Vec<PositionMargin>,
+    /// Total account initial margin (from the balance).
+    pub initial_margin: Decimal,
+    /// Sum of margin allocated to the given positions.
+    pub allocated_margin: Decimal,
+    /// Free margin not tied up by positions (equity - initial margin).
+    pub free_margin: Decimal,
+}
+
+/// Break down the account's initial margin by position.
+///
+/// Each position's share is its `margin` relative to `Balance::initial_margin`
+/// (zero when the account has no initial margin). Free margin is equity minus
+/// initial margin, which answers "which position is eating my margin".
+pub fn margin_breakdown(positions: &[Position], balance: &Balance) -> MarginBreakdown {
+    let initial_margin = balance.get_initial_margin();
+    let mut rows: Vec<PositionMargin> = positions
+        .iter()
+        .map(|p| {
+            let margin = p.get_margin();
+            let share = if initial_margin.is_zero() {
+                Decimal::ZERO
+            } else {
+                margin / initial_margin
+            };
+            PositionMargin {
+                market: p.market.clone(),
+                side: p.side,
+                margin,
+                share,
+            }
+        })
+        .collect();
+    rows.sort_by_key(|r| std::cmp::Reverse(r.margin));
+
+    MarginBreakdown {
+        allocated_margin: rows.iter().map(|r| r.margin).sum(),
+        positions: rows,
+        initial_margin,
+        free_margin: balance.equity - initial_margin,
+    }
+}
+
+/// Compute net directional exposure in USD, adjusted by per-market betas.
+///
+/// Sums each position's signed notional times the beta for its market. Markets
+/// missing from `betas` are counted with a beta of 1, so unmapped positions still
+/// show up at face value rather than silently disappearing.
+pub fn beta_adjusted_exposure(positions: &[Position], betas: &HashMap<String, Decimal>) -> Decimal {
+    positions
+        .iter()
+        .map(|p| {
+            let beta = betas.get(&p.market).copied().unwrap_or(Decimal::ONE);
+            p.signed_notional() * beta
+        })
+        .sum()
+}
+
+/// Get the size of the position a reduce-only order in `market` may close.
+fn reducible_size(
+    market: &str,
+    side: OrderSide,
+    positions: &[Position],
+) -> crate::error::Result<Decimal> {
+    let position = positions
+        .iter()
+        .find(|p| p.market == market && !p.size.is_zero())
+        .ok_or_else(|| {
+            crate::error::ExtendedError::OrderValidation(format!(
+                "Reduce-only {:?} order on {} has no open position to reduce",
+                side, market
+            ))
+        })?;
+    if position.closing_order_side() != side {
+        return Err(crate::error::ExtendedError::OrderValidation(format!(
+            "Reduce-only {:?} order on {} would increase the {:?} position",
+            side, market, position.side
+        )));
+    }
+    Ok(position.size.abs())
+}
+
+/// Check that a reduce-only order only reduces an open position.
+///
+/// The exchange rejects reduce-only orders with no opposite-side position to reduce;
+/// this catches them before signing. Fails with `ExtendedError::OrderValidation` if
+/// there is no position in the market, it is on the same side as the order, or the
+/// order is larger than the position.
+///
+/// # Arguments
+/// * `market` - Market of the order
+/// * `side` - Order side
+/// * `quantity` - Order quantity
+/// * `positions` - Open positions (e.g. from `PrivateApi::get_positions`)
+pub fn validate_reduce_only(
+    market: &str,
+    side: OrderSide,
+    quantity: Decimal,
+    positions: &[Position],
+) -> crate::error::Result<()> {
+    let size = reducible_size(market, side, positions)?;
+    if quantity > size {
+        return Err(crate::error::ExtendedError::OrderValidation(format!(
+            "Reduce-only order quantity {} exceeds the {} position size {}",
+            quantity, market, size
+        )));
+    }
+    Ok(())
+}
+
+/// Cap a reduce-only order's quantity to the size of the position it reduces.
+///
+/// Like [`validate_reduce_only`], but an order larger than the position is not an
+/// error: the position size is returned instead, so the order closes it exactly.
+pub fn cap_reduce_only(
+    market: &str,
+    side: OrderSide,
+    quantity: Decimal,
+    positions: &[Position],
+) -> crate::error::Result<Decimal> {
+    Ok(quantity.min(reducible_size(market, side, positions)?))
+}
+
 /// Historical position (closed).
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -179,6 +467,28 @@ pub struct GetPositionsParams {
     /// Filter by market.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub market: Option<String>,
+    /// Filter by side.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<PositionSide>,
+}
+
+impl GetPositionsParams {
+    /// Create empty parameters (no filters).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filter by market.
+    pub fn with_market(mut self, market: impl Into<String>) -> Self {
+        self.market = Some(market.into());
+        self
+    }
+
+    /// Filter by side, e.g. to fetch only longs.
+    pub fn with_side(mut self, side: PositionSide) -> Self {
+        self.side = Some(side);
+        self
+    }
 }
 
 /// Parameters for fetching position history.
@@ -195,3 +505,153 @@ pub struct GetPositionHistoryParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
 }
+
+impl GetPositionHistoryParams {
+    /// Create empty parameters (no filters).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filter by market.
+    pub fn with_market(mut self, market: impl Into<String>) -> Self {
+        self.market = Some(market.into());
+        self
+    }
+
+    /// Set the pagination cursor.
+    pub fn with_cursor(mut self, cursor: i64) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Set the maximum number of results.
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn position(side: &str, pnl: &str) -> Position {
+        serde_json::from_value(serde_json::json!({
+            "market": "BTC-USD",
+            "side": side,
+            "size": "1",
+            "openPrice": "99000",
+            "markPrice": "100000",
+            "unrealisedPnl": pnl,
+            "leverage": "10"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_reduce_only_validation() {
+        use crate::error::ExtendedError;
+
+        let long = [position("LONG", "0")];
+        let short = [position("SHORT", "0")];
+
+        assert!(validate_reduce_only("BTC-USD", OrderSide::Sell, dec!(0.5), &long).is_ok());
+        assert!(validate_reduce_only("BTC-USD", OrderSide::Buy, dec!(1), &short).is_ok());
+
+        // Same side as the position, no position, and larger than the position
+        for result in [
+            validate_reduce_only("BTC-USD", OrderSide::Buy, dec!(0.5), &long),
+            validate_reduce_only("BTC-USD", OrderSide::Sell, dec!(0.5), &short),
+            validate_reduce_only("ETH-USD", OrderSide::Sell, dec!(0.5), &long),
+            validate_reduce_only("BTC-USD", OrderSide::Sell, dec!(0.5), &[]),
+            validate_reduce_only("BTC-USD", OrderSide::Sell, dec!(1.5), &long),
+        ] {
+            assert!(matches!(result, Err(ExtendedError::OrderValidation(_))));
+        }
+
+        assert_eq!(
+            cap_reduce_only("BTC-USD", OrderSide::Sell, dec!(1.5), &long).unwrap(),
+            dec!(1)
+        );
+        assert_eq!(
+            cap_reduce_only("BTC-USD", OrderSide::Buy, dec!(0.25), &short).unwrap(),
+            dec!(0.25)
+        );
+        assert!(cap_reduce_only("BTC-USD", OrderSide::Buy, dec!(1.5), &long).is_err());
+    }
+
+    #[test]
+    fn test_funding_break_even() {
+        // Long pays 100000 * 0.0001 = 10 per hour; 1000 profit lasts 100 hours
+        let long = position("LONG", "1000");
+        assert_eq!(long.hourly_funding_cost(dec!(0.0001)), dec!(10));
+        assert_eq!(
+            long.funding_break_even(dec!(0.0001)),
+            Some(Duration::from_secs(100 * 3600))
+        );
+
+        // Shorts receive positive funding
+        assert_eq!(position("SHORT", "1000").funding_break_even(dec!(0.0001)), None);
+        assert_eq!(long.funding_break_even(Decimal::ZERO), None);
+
+        // Already underwater and paying: no time left
+        assert_eq!(
+            position("SHORT", "-50").funding_break_even(dec!(-0.0001)),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_projected_funding_cost() {
+        // 100000 notional at 0.0001 per hour: 10 per hour
+        let long = position("LONG", "0");
+        let short = position("SHORT", "0");
+        assert_eq!(long.projected_funding_cost(dec!(0.0001), 8), dec!(80));
+        assert_eq!(short.projected_funding_cost(dec!(0.0001), 8), dec!(-80));
+        // Negative rates flip who pays
+        assert_eq!(long.projected_funding_cost(dec!(-0.0001), 24), dec!(-240));
+        assert_eq!(short.projected_funding_cost(dec!(-0.0001), 24), dec!(240));
+        assert_eq!(long.projected_funding_cost(dec!(0.0001), 0), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_estimate_liquidation_price() {
+        // 10x long from 99000: 89100 / (1 - 0.01)
+        let mut long = position("LONG", "0");
+        assert_eq!(long.estimate_liquidation_price(dec!(0.01)), Some(dec!(90000)));
+        // No maintenance margin: liquidated once the whole margin is lost
+        assert_eq!(long.estimate_liquidation_price(Decimal::ZERO), Some(dec!(89100)));
+        assert_eq!(long.estimate_liquidation_price(Decimal::ONE), None);
+
+        // 10x short from 104000: 114400 / (1 + 0.04)
+        let mut short = position("SHORT", "0");
+        short.entry_price = dec!(104000);
+        assert_eq!(short.estimate_liquidation_price(dec!(0.04)), Some(dec!(110000)));
+        short.leverage = dec!(4);
+        assert_eq!(short.estimate_liquidation_price(dec!(0.04)), Some(dec!(125000)));
+
+        // The API's value wins when present
+        long.liquidation_price = Some(dec!(91000));
+        assert_eq!(long.liquidation_price_or_estimate(dec!(0.01)), Some(dec!(91000)));
+        long.liquidation_price = None;
+        assert_eq!(long.liquidation_price_or_estimate(dec!(0.01)), Some(dec!(90000)));
+        long.leverage = Decimal::ZERO;
+        assert_eq!(long.liquidation_price_or_estimate(dec!(0.01)), None);
+    }
+
+    #[test]
+    fn test_get_positions_params_builder() {
+        let params = GetPositionsParams::new().with_side(PositionSide::Short);
+        assert_eq!(serde_urlencoded::to_string(&params).unwrap(), "side=SHORT");
+        let params = params.with_market("BTC-USD").with_side(PositionSide::Long);
+        assert_eq!(serde_urlencoded::to_string(&params).unwrap(), "market=BTC-USD&side=LONG");
+        assert_eq!(serde_urlencoded::to_string(GetPositionsParams::new()).unwrap(), "");
+    }
+
+    #[test]
+    fn test_get_position_history_params_builder() {
+        let params = GetPositionHistoryParams::new().with_market("ETH-USD").with_limit(20);
+        assert_eq!(serde_urlencoded::to_string(&params).unwrap(), "market=ETH-USD&limit=20");
+    }
+}
@@ -47,6 +47,31 @@ pub struct PublicTrade {
     pub timestamp: i64,
 }
 
+/// Forced liquidation executed in a market (from the public liquidation feed).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Liquidation {
+    /// Market name.
+    pub market: String,
+    /// Side of the liquidation order (a SELL closes a liquidated long).
+    pub side: OrderSide,
+    /// Liquidated size in the synthetic asset.
+    #[serde(alias = "qty", deserialize_with = "decimal_from_string")]
+    pub size: Decimal,
+    /// Execution price.
+    #[serde(deserialize_with = "decimal_from_string")]
+    pub price: Decimal,
+    /// Liquidation timestamp (Unix ms).
+    pub timestamp: i64,
+}
+
+impl Liquidation {
+    /// Notional value of the liquidation (size × price).
+    pub fn notional(&self) -> Decimal {
+        self.size * self.price
+    }
+}
+
 /// User's trade (fill).
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -83,6 +108,17 @@ pub struct Trade {
 }
 
 impl Trade {
+    /// Get the liquidity role of this fill, if the API reported it.
+    pub fn role(&self) -> Option<TradeRole> {
+        self.is_maker.map(|is_maker| {
+            if is_maker {
+                TradeRole::Maker
+            } else {
+                TradeRole::Taker
+            }
+        })
+    }
+
     /// Get the trade value (price * quantity).
     pub fn value(&self) -> Decimal {
         self.price * self.quantity
@@ -103,6 +139,124 @@ impl Trade {
     }
 }
 
+/// Liquidity role of a fill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeRole {
+    /// Fill provided liquidity (resting order).
+    Maker,
+    /// Fill removed liquidity (aggressing order).
+    Taker,
+}
+
+/// Collection of trades with helper methods.
+#[derive(Debug, Clone)]
+pub struct Trades(pub Vec<Trade>);
+
+impl Trades {
+    /// Iterate over maker fills.
+    pub fn makers(&self) -> impl Iterator<Item = &Trade> {
+        self.with_role(TradeRole::Maker)
+    }
+
+    /// Iterate over taker fills.
+    pub fn takers(&self) -> impl Iterator<Item = &Trade> {
+        self.with_role(TradeRole::Taker)
+    }
+
+    /// Iterate over fills with the given role.
+    ///
+    /// Fills whose role was not reported by the API are skipped.
+    pub fn with_role(&self, role: TradeRole) -> impl Iterator<Item = &Trade> {
+        self.0.iter().filter(move |t| t.role() == Some(role))
+    }
+
+    /// Split into (maker, taker) fills. Fills with an unknown role are dropped.
+    pub fn partition_by_role(self) -> (Trades, Trades) {
+        let (makers, takers): (Vec<Trade>, Vec<Trade>) = self
+            .0
+            .into_iter()
+            .filter(|t| t.role().is_some())
+            .partition(|t| t.role() == Some(TradeRole::Maker));
+        (Trades(makers), Trades(takers))
+    }
+
+    /// Get the total filled quantity.
+    pub fn total_quantity(&self) -> Decimal {
+        self.0.iter().map(|t| t.quantity).sum()
+    }
+
+    /// Get the quantity-weighted average fill price.
+    ///
+    /// Returns `None` when there are no fills (or total quantity is zero).
+    pub fn weighted_average_price(&self) -> Option<Decimal> {
+        let total_qty = self.total_quantity();
+        if total_qty.is_zero() {
+            return None;
+        }
+        let total_value: Decimal = self.0.iter().map(|t| t.value()).sum();
+        Some(total_value / total_qty)
+    }
+
+    /// Iterate over all trades.
+    pub fn iter(&self) -> impl Iterator<Item = &Trade> {
+        self.0.iter()
+    }
+
+    /// Number of trades.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Relative difference between the computed and reported average fill prices above which
+/// [`AvgFillPrice::mismatch`] is set (0.01%), leaving room for the exchange's rounding.
+pub const AVG_FILL_PRICE_TOLERANCE: Decimal = Decimal::from_parts(1, 0, 0, false, 4);
+
+/// Average fill price of an order, computed from its fills and as reported by the exchange.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AvgFillPrice {
+    /// Quantity-weighted average price of the fills, `None` without fills.
+    pub computed: Option<Decimal>,
+    /// The order's `average_price` as reported by the exchange.
+    pub reported: Option<Decimal>,
+    /// Whether both prices are known and differ by more than [`AVG_FILL_PRICE_TOLERANCE`].
+    pub mismatch: bool,
+}
+
+impl AvgFillPrice {
+    /// Compare the average price of `fills` with the exchange's reported average.
+    pub fn new(fills: &Trades, reported: Option<Decimal>) -> Self {
+        let computed = fills.weighted_average_price();
+        let mismatch = match (computed, reported) {
+            (Some(computed), Some(reported)) => {
+                (computed - reported).abs() > reported.abs() * AVG_FILL_PRICE_TOLERANCE
+            }
+            _ => false,
+        };
+        Self {
+            computed,
+            reported,
+            mismatch,
+        }
+    }
+
+    /// Get the best known average: the computed one, or the reported one without fills.
+    pub fn price(&self) -> Option<Decimal> {
+        self.computed.or(self.reported)
+    }
+}
+
+impl From<Vec<Trade>> for Trades {
+    fn from(v: Vec<Trade>) -> Self {
+        Self(v)
+    }
+}
+
 /// Funding payment.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -134,7 +288,27 @@ impl FundingPayment {
     }
 }
 
+/// Sum the funding payments, positive for net funding received.
+///
+/// Follows `FundingPayment::payment`, so a result above zero means the payments were
+/// net received and below zero net paid. `Position::projected_funding_cost` uses the
+/// opposite sign (positive when paying).
+pub fn total_funding(payments: &[FundingPayment]) -> Decimal {
+    payments.iter().map(|p| p.payment).sum()
+}
+
 /// Parameters for fetching trades.
+///
+/// # Example
+/// ```
+/// use extended_rust_sdk::models::GetTradesParams;
+///
+/// let params = GetTradesParams::new()
+///     .with_market("ETH-USD")
+///     .with_range(1_700_000_000_000, 1_700_086_400_000)
+///     .with_limit(100);
+/// assert_eq!(params.limit, Some(100));
+/// ```
 #[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetTradesParams {
@@ -158,6 +332,44 @@ pub struct GetTradesParams {
     pub limit: Option<u32>,
 }
 
+impl GetTradesParams {
+    /// Create empty parameters (no filters).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filter by market.
+    pub fn with_market(mut self, market: impl Into<String>) -> Self {
+        self.market = Some(market.into());
+        self
+    }
+
+    /// Filter by order ID.
+    pub fn with_order_id(mut self, order_id: impl Into<String>) -> Self {
+        self.order_id = Some(order_id.into());
+        self
+    }
+
+    /// Set the time range (Unix ms).
+    pub fn with_range(mut self, start: i64, end: i64) -> Self {
+        self.start_time = Some(start);
+        self.end_time = Some(end);
+        self
+    }
+
+    /// Set the pagination cursor.
+    pub fn with_cursor(mut self, cursor: i64) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Set the maximum number of results.
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
 /// Parameters for fetching public trades.
 #[derive(Debug, Clone, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -174,6 +386,12 @@ pub struct GetFundingHistoryParams {
     /// Filter by market.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub market: Option<String>,
+    /// Start timestamp (Unix ms).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// End timestamp (Unix ms).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
     /// Pagination cursor.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cursor: Option<i64>,
@@ -181,3 +399,125 @@ pub struct GetFundingHistoryParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
 }
+
+impl GetFundingHistoryParams {
+    /// Create empty parameters (no filters).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filter by market.
+    pub fn with_market(mut self, market: impl Into<String>) -> Self {
+        self.market = Some(market.into());
+        self
+    }
+
+    /// Set the time range (Unix ms).
+    pub fn with_range(mut self, start: i64, end: i64) -> Self {
+        self.start_time = Some(start);
+        self.end_time = Some(end);
+        self
+    }
+
+    /// Set the pagination cursor.
+    pub fn with_cursor(mut self, cursor: i64) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Set the maximum number of results.
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_avg_fill_price_mismatch() {
+        let fill = |price: Decimal, quantity: Decimal| Trade {
+            id: "1".to_string(),
+            order_id: Some("77".to_string()),
+            market: "BTC-USD".to_string(),
+            side: OrderSide::Buy,
+            price,
+            quantity,
+            fee: None,
+            fee_asset: None,
+            is_maker: None,
+            realized_pnl: None,
+            timestamp: 0,
+        };
+        let fills = Trades(vec![fill(dec!(100000), dec!(0.01)), fill(dec!(100300), dec!(0.02))]);
+
+        let avg = AvgFillPrice::new(&fills, Some(dec!(100200)));
+        assert_eq!(avg.computed, Some(dec!(100200)));
+        assert!(!avg.mismatch);
+        // Rounding within the tolerance is fine
+        assert!(!AvgFillPrice::new(&fills, Some(dec!(100195))).mismatch);
+        assert!(AvgFillPrice::new(&fills, Some(dec!(100100))).mismatch);
+
+        // Either side missing can't be cross-checked
+        let unreported = AvgFillPrice::new(&fills, None);
+        assert!(!unreported.mismatch);
+        assert_eq!(unreported.price(), Some(dec!(100200)));
+        let unfilled = AvgFillPrice::new(&Trades(vec![]), Some(dec!(100000)));
+        assert_eq!((unfilled.computed, unfilled.price()), (None, Some(dec!(100000))));
+        assert!(!unfilled.mismatch);
+    }
+
+    fn funding_payment(size: &str, payment: &str) -> FundingPayment {
+        serde_json::from_value(serde_json::json!({
+            "market": "BTC-USD",
+            "fundingRate": "0.0001",
+            "positionSize": size,
+            "payment": payment,
+            "timestamp": 1_700_000_000_000i64
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_total_funding() {
+        // A long paying 10 an hour for three hours
+        let long = vec![funding_payment("1", "-10"); 3];
+        assert!(long.iter().all(FundingPayment::is_paid));
+        assert_eq!(total_funding(&long), dec!(-30));
+
+        // A short receiving, with one hour paid after the rate flipped
+        let short = [
+            funding_payment("-1", "10"),
+            funding_payment("-1", "10"),
+            funding_payment("-1", "-5"),
+        ];
+        assert_eq!(total_funding(&short), dec!(15));
+        assert_eq!(total_funding(&[]), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_history_params_builders() {
+        let trades = GetTradesParams::new()
+            .with_market("BTC-USD")
+            .with_order_id("1001")
+            .with_range(1_700_000_000_000, 1_700_003_600_000)
+            .with_cursor(7)
+            .with_limit(100);
+        assert_eq!(
+            serde_urlencoded::to_string(&trades).unwrap(),
+            "market=BTC-USD&orderId=1001&startTime=1700000000000&endTime=1700003600000\
+             &cursor=7&limit=100"
+        );
+
+        let funding = GetFundingHistoryParams::new().with_market("SOL-USD").with_cursor(3);
+        assert_eq!(serde_urlencoded::to_string(&funding).unwrap(), "market=SOL-USD&cursor=3");
+        let funding = funding.with_range(1_700_000_000_000, 1_700_003_600_000);
+        assert_eq!(
+            serde_urlencoded::to_string(&funding).unwrap(),
+            "market=SOL-USD&startTime=1700000000000&endTime=1700003600000&cursor=3"
+        );
+    }
+}
@@ -3,6 +3,8 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::error::{ExtendedError, Result};
+
 /// Withdrawal request.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -64,6 +66,13 @@ pub enum WithdrawalStatus {
     Failed,
 }
 
+impl WithdrawalStatus {
+    /// Check if the withdrawal is final (completed or failed).
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed)
+    }
+}
+
 /// Transfer request (between sub-accounts).
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -127,6 +136,38 @@ pub struct BridgeConfig {
     pub chains: Vec<BridgeChain>,
 }
 
+impl BridgeConfig {
+    /// Get a supported chain by ID.
+    pub fn chain(&self, chain_id: u64) -> Option<&BridgeChain> {
+        self.chains.iter().find(|c| c.chain_id == chain_id)
+    }
+
+    /// Check that a quote request targets a supported chain with deposits enabled.
+    ///
+    /// Fails with `ExtendedError::InvalidParameter` naming the supported chains otherwise.
+    pub fn check_quote_request(&self, request: &BridgeQuoteRequest) -> Result<()> {
+        match self.chain(request.chain_id) {
+            Some(chain) if chain.deposits_enabled => Ok(()),
+            Some(chain) => Err(ExtendedError::InvalidParameter(format!(
+                "Bridge deposits from {} (chain {}) are currently disabled",
+                chain.name, chain.chain_id
+            ))),
+            None => {
+                let supported: Vec<String> = self
+                    .chains
+                    .iter()
+                    .map(|c| format!("{} ({})", c.name, c.chain_id))
+                    .collect();
+                Err(ExtendedError::InvalidParameter(format!(
+                    "Chain {} is not supported by the bridge; supported chains: {}",
+                    request.chain_id,
+                    supported.join(", ")
+                )))
+            }
+        }
+    }
+}
+
 /// Supported bridge chain.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
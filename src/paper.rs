@@ -0,0 +1,358 @@
+//! Paper trading: orders are filled locally against live market data.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use futures::future::try_join_all;
+use rust_decimal::Decimal;
+
+use crate::api::PublicApi;
+use crate::client::HttpClient;
+use crate::config::EndpointConfig;
+use crate::error::{ExtendedError, OrderRejectReason, Result};
+use crate::models::{
+    Balance, CreateOrderRequest, OrderBook, OrderSide, OrderType, PlacedOrderResponse, Position,
+    PositionSide, TimeInForce, DEFAULT_FEE_RATE,
+};
+
+/// Simulated position, with a signed size (negative for shorts).
+#[derive(Debug, Clone, Copy, Default)]
+struct PaperPosition {
+    size: Decimal,
+    entry_price: Decimal,
+    realized_pnl: Decimal,
+}
+
+#[derive(Debug, Default)]
+struct PaperState {
+    balance: Decimal,
+    positions: HashMap<String, PaperPosition>,
+    next_order_id: u64,
+}
+
+impl PaperState {
+    /// Apply a fill of `quantity` at `price`, charging `fee_rate` on the notional.
+    fn apply_fill(
+        &mut self,
+        market: &str,
+        side: OrderSide,
+        quantity: Decimal,
+        price: Decimal,
+        fee_rate: Decimal,
+    ) {
+        let position = self.positions.entry(market.to_string()).or_default();
+        let delta = match side {
+            OrderSide::Buy => quantity,
+            OrderSide::Sell => -quantity,
+        };
+
+        if position.size.is_zero() || position.size.is_sign_positive() == delta.is_sign_positive() {
+            // Opening or increasing: blend the entry price
+            let total = position.size.abs() + quantity;
+            position.entry_price =
+                (position.size.abs() * position.entry_price + quantity * price) / total;
+        } else {
+            // Reducing, closing or flipping: realize PnL on the closed part
+            let closed = quantity.min(position.size.abs());
+            let direction = if position.size.is_sign_positive() { 1 } else { -1 };
+            let pnl = closed * (price - position.entry_price) * Decimal::from(direction);
+            position.realized_pnl += pnl;
+            self.balance += pnl;
+            if quantity > closed {
+                position.entry_price = price;
+            }
+        }
+        position.size += delta;
+
+        self.balance -= quantity * price * fee_rate;
+        if position.size.is_zero() {
+            self.positions.remove(market);
+        }
+    }
+}
+
+/// Fill `quantity` against the book, taking only levels at or better than `limit`.
+///
+/// Returns the filled quantity and its average price, or `None` if nothing fills.
+fn fill_within_limit(
+    book: &OrderBook,
+    side: OrderSide,
+    quantity: Decimal,
+    limit: Decimal,
+) -> Option<(Decimal, Decimal)> {
+    let mut remaining = quantity;
+    let mut cost = Decimal::ZERO;
+    for level in book.levels_for(side) {
+        let acceptable = match side {
+            OrderSide::Buy => level.price <= limit,
+            OrderSide::Sell => level.price >= limit,
+        };
+        if !acceptable || remaining.is_zero() {
+            break;
+        }
+        let take = remaining.min(level.quantity);
+        cost += take * level.price;
+        remaining -= take;
+    }
+    let filled = quantity - remaining;
+    (!filled.is_zero()).then(|| (filled, cost / filled))
+}
+
+/// Client that simulates order execution locally, for testing strategies without funds.
+///
+/// Mirrors the order, position and balance calls of `PrivateApi`, but `create_order`
+/// walks the live order book instead of sending the order: it fills immediately at the
+/// levels within the order's price (the worst acceptable price for market orders) and
+/// drops any remainder, like an immediate-or-cancel order (fill-or-kill orders that can't
+/// fill in full are rejected instead). Resting orders aren't
+/// simulated, so post-only orders are always rejected (as `PostOnlyCrossed` when they
+/// would cross), as are conditional and TP/SL orders. Orders don't need to be signed.
+///
+/// Positions aren't margined or liquidated; the balance only moves by realized PnL and
+/// fees. Clones share the same simulated account.
+///
+/// # Example
+/// ```no_run
+/// # async fn example() -> extended_rust_sdk::error::Result<()> {
+/// use extended_rust_sdk::config::mainnet_config;
+/// use extended_rust_sdk::models::{OrderBuilder, OrderSide};
+/// use extended_rust_sdk::paper::PaperClient;
+/// use rust_decimal_macros::dec;
+///
+/// let paper = PaperClient::new(mainnet_config(), dec!(10000))?;
+/// let order = OrderBuilder::market("BTC-USD", OrderSide::Buy, dec!(0.01), dec!(101000)).build();
+/// paper.create_order(order).await?;
+/// println!("{:?}", paper.get_positions().await?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PaperClient {
+    public: PublicApi,
+    fee_rate: Decimal,
+    state: Arc<Mutex<PaperState>>,
+}
+
+impl PaperClient {
+    /// Create a paper client reading market data from the given endpoint.
+    ///
+    /// # Arguments
+    /// * `config` - Endpoint configuration used for market data
+    /// * `starting_balance` - Initial simulated collateral balance
+    pub fn new(config: EndpointConfig, starting_balance: Decimal) -> Result<Self> {
+        let public = PublicApi::new(HttpClient::new(config)?);
+        let state = PaperState {
+            balance: starting_balance,
+            ..Default::default()
+        };
+        Ok(Self {
+            public,
+            fee_rate: DEFAULT_FEE_RATE,
+            state: Arc::new(Mutex::new(state)),
+        })
+    }
+
+    /// Set the fee rate charged on simulated fills (default is DEFAULT_FEE_RATE).
+    pub fn with_fee_rate(mut self, fee_rate: Decimal) -> Self {
+        self.fee_rate = fee_rate;
+        self
+    }
+
+    /// Get the public API used for market data.
+    pub fn public(&self) -> &PublicApi {
+        &self.public
+    }
+
+    fn state(&self) -> MutexGuard<'_, PaperState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Simulate an order against the current order book.
+    ///
+    /// Fails with `ExtendedError::OrderValidation` if the order type isn't simulated,
+    /// nothing fills within its price, or a reduce-only order has no position to reduce.
+    pub async fn create_order(&self, order: CreateOrderRequest) -> Result<PlacedOrderResponse> {
+        if matches!(order.order_type, OrderType::Conditional | OrderType::Tpsl)
+            || order.take_profit.is_some()
+            || order.stop_loss.is_some()
+        {
+            return Err(ExtendedError::OrderValidation(
+                "paper trading only simulates limit and market orders".to_string(),
+            ));
+        }
+
+        let book = self.public.get_orderbook(&order.market, None).await?;
+        if order.post_only {
+            if fill_within_limit(&book, order.side, order.quantity, order.price).is_some() {
+                return Err(ExtendedError::OrderRejected {
+                    reason: OrderRejectReason::PostOnlyCrossed,
+                    message: "post-only order would cross the book".to_string(),
+                });
+            }
+            return Err(ExtendedError::OrderValidation(
+                "paper trading doesn't simulate resting orders".to_string(),
+            ));
+        }
+
+        let mut state = self.state();
+        let mut quantity = order.quantity;
+        if order.reduce_only {
+            let size = state.positions.get(&order.market).map_or(Decimal::ZERO, |p| p.size);
+            let reduces = match order.side {
+                OrderSide::Buy => size.is_sign_negative(),
+                OrderSide::Sell => size.is_sign_positive(),
+            };
+            if size.is_zero() || !reduces {
+                return Err(ExtendedError::OrderValidation(format!(
+                    "reduce-only order has no {} position to reduce",
+                    order.market
+                )));
+            }
+            quantity = quantity.min(size.abs());
+        }
+
+        let (filled, price) = fill_within_limit(&book, order.side, quantity, order.price)
+            .ok_or_else(|| {
+                ExtendedError::OrderValidation(format!(
+                    "no liquidity in {} at or better than {}",
+                    order.market, order.price
+                ))
+            })?;
+        if order.time_in_force == TimeInForce::FillOrKill && filled < quantity {
+            return Err(ExtendedError::OrderValidation(format!(
+                "fill-or-kill order for {} {} would only fill {}",
+                quantity, order.market, filled
+            )));
+        }
+        state.apply_fill(&order.market, order.side, filled, price, self.fee_rate);
+        state.next_order_id += 1;
+
+        Ok(PlacedOrderResponse {
+            id: state.next_order_id.to_string(),
+            external_id: order.id,
+        })
+    }
+
+    /// Get the simulated open positions, marked at the current mark prices.
+    pub async fn get_positions(&self) -> Result<Vec<Position>> {
+        let positions: Vec<(String, PaperPosition)> =
+            self.state().positions.iter().map(|(m, p)| (m.clone(), *p)).collect();
+        let stats = try_join_all(
+            positions.iter().map(|(market, _)| self.public.get_market_stats(market)),
+        )
+        .await?;
+
+        let mut result: Vec<Position> = positions
+            .into_iter()
+            .zip(stats)
+            .map(|((market, p), stats)| {
+                let mark_price = stats.mark_price;
+                Position {
+                    id: None,
+                    market,
+                    side: if p.size.is_sign_positive() {
+                        PositionSide::Long
+                    } else {
+                        PositionSide::Short
+                    },
+                    size: p.size.abs(),
+                    entry_price: p.entry_price,
+                    mark_price,
+                    liquidation_price: None,
+                    unrealized_pnl: p.size * (mark_price - p.entry_price),
+                    realized_pnl: Some(p.realized_pnl),
+                    margin: None,
+                    value: Some(p.size.abs() * mark_price),
+                    leverage: Decimal::ONE,
+                    adl: None,
+                    created_at: None,
+                    updated_at: None,
+                }
+            })
+            .collect();
+        result.sort_by(|a, b| a.market.cmp(&b.market));
+        Ok(result)
+    }
+
+    /// Get the simulated balance, with equity including unrealized PnL.
+    pub async fn get_balance(&self) -> Result<Balance> {
+        let positions = self.get_positions().await?;
+        let balance = self.state().balance;
+        let unrealized_pnl: Decimal = positions.iter().map(|p| p.unrealized_pnl).sum();
+        let total_exposure: Decimal = positions.iter().map(|p| p.notional()).sum();
+
+        Ok(Balance {
+            collateral_name: Some("USD".to_string()),
+            balance,
+            status: Some("ACTIVE".to_string()),
+            equity: balance + unrealized_pnl,
+            spot_equity: None,
+            unrealized_pnl: Some(unrealized_pnl),
+            initial_margin: None,
+            maintenance_margin: None,
+            available_for_trade: Some(balance + unrealized_pnl),
+            available_for_withdrawal: Some(balance.min(balance + unrealized_pnl)),
+            margin_ratio: None,
+            account_leverage: None,
+            total_exposure: Some(total_exposure),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::PriceQuantity;
+    use rust_decimal_macros::dec;
+
+    fn book() -> OrderBook {
+        let level = |price: Decimal, quantity: Decimal| PriceQuantity { price, quantity };
+        OrderBook {
+            market: "BTC-USD".to_string(),
+            bids: vec![level(dec!(99), dec!(1)), level(dec!(98), dec!(2))],
+            asks: vec![level(dec!(101), dec!(1)), level(dec!(102), dec!(2))],
+            timestamp: 0,
+            sequence: None,
+        }
+    }
+
+    #[test]
+    fn test_fill_within_limit() {
+        let book = book();
+        assert_eq!(
+            fill_within_limit(&book, OrderSide::Buy, dec!(2), dec!(105)),
+            Some((dec!(2), dec!(101.5)))
+        );
+        // Only the first level is within the limit; the rest is dropped
+        assert_eq!(
+            fill_within_limit(&book, OrderSide::Sell, dec!(3), dec!(99)),
+            Some((dec!(1), dec!(99)))
+        );
+        assert_eq!(fill_within_limit(&book, OrderSide::Buy, dec!(1), dec!(100)), None);
+    }
+
+    #[test]
+    fn test_apply_fill_tracks_entry_and_realized_pnl() {
+        let mut state = PaperState {
+            balance: dec!(1000),
+            ..Default::default()
+        };
+        state.apply_fill("BTC-USD", OrderSide::Buy, dec!(1), dec!(100), Decimal::ZERO);
+        state.apply_fill("BTC-USD", OrderSide::Buy, dec!(1), dec!(110), Decimal::ZERO);
+        let position = state.positions["BTC-USD"];
+        assert_eq!(position.size, dec!(2));
+        assert_eq!(position.entry_price, dec!(105));
+
+        // Sell 3: closes 2 for +10 each and flips to 1 short at 115
+        state.apply_fill("BTC-USD", OrderSide::Sell, dec!(3), dec!(115), Decimal::ZERO);
+        let position = state.positions["BTC-USD"];
+        assert_eq!(position.size, dec!(-1));
+        assert_eq!(position.entry_price, dec!(115));
+        assert_eq!(state.balance, dec!(1020));
+
+        // Closing the short at 120 loses 5, and the fee is charged on the notional
+        state.apply_fill("BTC-USD", OrderSide::Buy, dec!(1), dec!(120), dec!(0.001));
+        assert!(state.positions.is_empty());
+        assert_eq!(state.balance, dec!(1014.88));
+    }
+}
@@ -0,0 +1,65 @@
+//! Export and import of signed orders as NDJSON batches.
+
+use crate::error::{ExtendedError, Result};
+use crate::models::CreateOrderRequest;
+
+/// Serialize orders as NDJSON (one JSON order per line).
+///
+/// The output contains each order exactly as it would be sent to the API, including
+/// its settlement signature, so it can be replayed with [`import_batch`] later or on
+/// another machine.
+pub fn export_batch(orders: &[CreateOrderRequest]) -> Result<String> {
+    let mut out = String::new();
+    for order in orders {
+        out.push_str(&serde_json::to_string(order)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Parse orders from an NDJSON batch produced by [`export_batch`].
+///
+/// Blank lines are skipped. Parse errors report the 1-based line number.
+pub fn import_batch(s: &str) -> Result<Vec<CreateOrderRequest>> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|e| {
+                ExtendedError::InvalidParameter(format!("batch line {}: {}", i + 1, e))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{OrderBuilder, OrderSide};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_batch_round_trip() {
+        let orders = vec![
+            OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000), dec!(0.01), true, false)
+                .nonce(1)
+                .build(),
+            OrderBuilder::limit("ETH-USD", OrderSide::Sell, dec!(3500.5), dec!(1.25), false, true)
+                .nonce(2)
+                .build(),
+        ];
+
+        let batch = export_batch(&orders).unwrap();
+        assert_eq!(batch.lines().count(), 2);
+
+        let imported = import_batch(&format!("{}\n", batch)).unwrap();
+        assert_eq!(imported.len(), 2);
+        assert_eq!(imported[1].market, "ETH-USD");
+        assert_eq!(imported[1].price, dec!(3500.5));
+        assert!(imported[1].reduce_only);
+        assert_eq!(export_batch(&imported).unwrap(), batch);
+
+        let err = import_batch(&format!("{}not json\n", batch)).unwrap_err();
+        assert!(err.to_string().contains("line 3"), "{}", err);
+    }
+}
@@ -0,0 +1,225 @@
+//! Stark key derivation from an Ethereum account, for onboarding.
+//!
+//! Extended derives an account's Stark key from the Ethereum signature of an EIP-712
+//! `AccountCreation` message, so the same wallet always recovers the same key.
+
+use std::str::FromStr;
+
+use alloy::primitives::{hex, keccak256, Address, B256};
+use alloy::signers::local::coins_bip39::English;
+use alloy::signers::local::{MnemonicBuilder, PrivateKeySigner};
+use alloy::signers::SignerSync;
+use starknet::core::types::Felt;
+use starknet_crypto::pedersen_hash;
+
+use super::{get_private_key_from_eth_signature, StarkSigner};
+use crate::error::{ExtendedError, Result};
+use crate::models::{AccountCreation, OnboardingSignature, StarkKeyRegistration};
+
+/// EIP-712 domain name of the onboarding message on mainnet.
+pub const MAINNET_ONBOARDING_DOMAIN: &str = "extended.exchange";
+
+/// EIP-712 domain name of the onboarding message on testnet (Sepolia).
+pub const TESTNET_ONBOARDING_DOMAIN: &str = "starknet.sepolia.extended.exchange";
+
+/// EIP-712 type of the domain, which only has a name.
+const DOMAIN_TYPE: &str = "EIP712Domain(string name)";
+
+/// EIP-712 type of the onboarding message.
+const ACCOUNT_CREATION_TYPE: &str =
+    "AccountCreation(int8 accountIndex,address wallet,bool tosAccepted)";
+
+/// Stark key derived from an Ethereum account, with the signature it came from.
+///
+/// Pass it to `PrivateApi::register_stark_key` to onboard.
+#[derive(Debug, Clone)]
+pub struct EthDerivedKey {
+    /// Stark signer holding the derived key pair.
+    pub signer: StarkSigner,
+    /// Ethereum signature of the onboarding message (0x-prefixed hex, `r || s || v`).
+    pub eth_signature: String,
+    /// Checksummed address of the Ethereum account.
+    pub eth_address: String,
+    /// Sub-account index the onboarding message was signed for.
+    pub account_index: u8,
+}
+
+impl EthDerivedKey {
+    /// Build the onboarding request that registers this key.
+    ///
+    /// Carries the signed `AccountCreation` message and its Ethereum signature, plus a
+    /// Stark signature of `pedersen(wallet, stark_public_key)` proving the Stark key is
+    /// held by the same party.
+    pub fn registration(&self) -> Result<StarkKeyRegistration> {
+        let wallet = Felt::from_hex(&self.eth_address).map_err(|e| {
+            ExtendedError::Signing(format!("Invalid wallet address {}: {:?}", self.eth_address, e))
+        })?;
+        let (r, s) = self.signer.sign(&pedersen_hash(&wallet, self.signer.public_key()))?;
+
+        Ok(StarkKeyRegistration {
+            l1_signature: self.eth_signature.clone(),
+            l2_key: self.signer.public_key_hex(),
+            l2_signature: OnboardingSignature {
+                r: format!("{:#x}", r),
+                s: format!("{:#x}", s),
+            },
+            account_creation: AccountCreation {
+                account_index: self.account_index,
+                wallet: self.eth_address.clone(),
+                tos_accepted: true,
+            },
+        })
+    }
+}
+
+/// Compute the EIP-712 digest of the onboarding message:
+///
+/// ```text
+/// domain:  { name: <domain> }
+/// message: AccountCreation { accountIndex: <account_index>, wallet: <address>, tosAccepted: true }
+/// ```
+fn onboarding_hash(wallet: Address, account_index: u8, domain: &str) -> B256 {
+    let domain_separator = keccak256(
+        [keccak256(DOMAIN_TYPE).as_slice(), keccak256(domain).as_slice()].concat(),
+    );
+
+    let mut account_index_word = [0u8; 32];
+    account_index_word[31] = account_index;
+    let mut wallet_word = [0u8; 32];
+    wallet_word[12..].copy_from_slice(wallet.as_slice());
+    let mut tos_accepted_word = [0u8; 32];
+    tos_accepted_word[31] = 1;
+    let struct_hash = keccak256(
+        [
+            keccak256(ACCOUNT_CREATION_TYPE).as_slice(),
+            &account_index_word,
+            &wallet_word,
+            &tos_accepted_word,
+        ]
+        .concat(),
+    );
+
+    keccak256([&[0x19, 0x01], domain_separator.as_slice(), struct_hash.as_slice()].concat())
+}
+
+fn derive_with_signer(
+    eth_signer: &PrivateKeySigner,
+    account_index: u8,
+    domain: &str,
+) -> Result<EthDerivedKey> {
+    if account_index > i8::MAX as u8 {
+        return Err(ExtendedError::InvalidParameter(format!(
+            "account index {} does not fit the message's int8 field",
+            account_index
+        )));
+    }
+
+    let wallet = eth_signer.address();
+    let hash = onboarding_hash(wallet, account_index, domain);
+    let signature = eth_signer
+        .sign_hash_sync(&hash)
+        .map_err(|e| ExtendedError::Signing(format!("Failed to sign onboarding message: {}", e)))?;
+    let eth_signature = hex::encode_prefixed(signature.as_bytes());
+
+    let signer = StarkSigner::new(get_private_key_from_eth_signature(&eth_signature)?)?;
+    Ok(EthDerivedKey {
+        signer,
+        eth_signature,
+        eth_address: wallet.to_checksum(None),
+        account_index,
+    })
+}
+
+/// Derive an account's Stark key from an Ethereum private key.
+///
+/// Signs the onboarding message for `account_index` with the Ethereum key and derives
+/// the Stark key from the signature, as the Extended web app does when connecting a
+/// wallet.
+///
+/// # Arguments
+/// * `eth_private_key` - Ethereum private key (hex, with or without `0x`)
+/// * `account_index` - Sub-account index (0 for the main account, at most 127)
+/// * `domain` - [`MAINNET_ONBOARDING_DOMAIN`] or [`TESTNET_ONBOARDING_DOMAIN`]
+pub fn derive_stark_key_from_eth_key(
+    eth_private_key: &str,
+    account_index: u8,
+    domain: &str,
+) -> Result<EthDerivedKey> {
+    let eth_signer = PrivateKeySigner::from_str(eth_private_key)
+        .map_err(|e| ExtendedError::Signing(format!("Invalid Ethereum private key: {}", e)))?;
+    derive_with_signer(&eth_signer, account_index, domain)
+}
+
+/// Derive an account's Stark key from a BIP-39 mnemonic.
+///
+/// The Ethereum key is taken from the standard derivation path `m/44'/60'/0'/0/<wallet_index>`
+/// (wallet index 0 is the first address in most wallets), then used as in
+/// [`derive_stark_key_from_eth_key`].
+///
+/// # Arguments
+/// * `phrase` - English mnemonic phrase
+/// * `wallet_index` - Address index within the wallet
+/// * `account_index` - Sub-account index (0 for the main account, at most 127)
+/// * `domain` - [`MAINNET_ONBOARDING_DOMAIN`] or [`TESTNET_ONBOARDING_DOMAIN`]
+pub fn derive_stark_key_from_mnemonic(
+    phrase: &str,
+    wallet_index: u32,
+    account_index: u8,
+    domain: &str,
+) -> Result<EthDerivedKey> {
+    let eth_signer = MnemonicBuilder::<English>::default()
+        .phrase(phrase)
+        .index(wallet_index)
+        .and_then(|builder| builder.build())
+        .map_err(|e| ExtendedError::Signing(format!("Invalid mnemonic: {}", e)))?;
+    derive_with_signer(&eth_signer, account_index, domain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// First account of the well-known Hardhat/Anvil test mnemonic.
+    const ETH_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    #[test]
+    fn test_onboarding_test_vector() {
+        let derived = derive_stark_key_from_eth_key(ETH_KEY, 0, MAINNET_ONBOARDING_DOMAIN).unwrap();
+        assert_eq!(derived.eth_address, "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+        assert_eq!(
+            derived.eth_signature,
+            "0x913ccc52504b99a0f1813d4580f840f4eb87ae2c804b3523a7d6be69976b5e69\
+             03bbc205592922f350c459b0f45a62ee9d67bcb8a0bf615589fd7c692ed92e6e1b"
+        );
+        let expected = get_private_key_from_eth_signature(&derived.eth_signature).unwrap();
+        assert_eq!(
+            derived.signer.public_key_hex(),
+            StarkSigner::new(expected).unwrap().public_key_hex()
+        );
+
+        let testnet =
+            derive_stark_key_from_eth_key(ETH_KEY, 1, TESTNET_ONBOARDING_DOMAIN).unwrap();
+        assert_eq!(
+            testnet.eth_signature,
+            "0x5fa5813bffcbe47274fdcac326f539907bae557369bf565110bfb2d7eb3b35c7\
+             39ac934147fa0ddcabb9f5b6160165f7a6559b8f5a185c094ec9434b80ca3fba1c"
+        );
+
+        let mnemonic = "test test test test test test test test test test test junk";
+        let from_mnemonic =
+            derive_stark_key_from_mnemonic(mnemonic, 0, 0, MAINNET_ONBOARDING_DOMAIN).unwrap();
+        assert_eq!(from_mnemonic.eth_signature, derived.eth_signature);
+    }
+
+    #[test]
+    fn test_rejects_bad_input() {
+        assert!(matches!(
+            derive_stark_key_from_eth_key(ETH_KEY, 128, MAINNET_ONBOARDING_DOMAIN),
+            Err(ExtendedError::InvalidParameter(_))
+        ));
+        assert!(matches!(
+            derive_stark_key_from_eth_key("0x1234", 0, MAINNET_ONBOARDING_DOMAIN),
+            Err(ExtendedError::Signing(_))
+        ));
+    }
+}
@@ -1,10 +1,19 @@
 //! Signing and cryptographic utilities.
 
+mod batch;
+mod eth;
+mod secret;
 mod stark;
 
+pub use batch::{export_batch, import_batch};
+pub use eth::{
+    derive_stark_key_from_eth_key, derive_stark_key_from_mnemonic, EthDerivedKey,
+    MAINNET_ONBOARDING_DOMAIN, TESTNET_ONBOARDING_DOMAIN,
+};
+pub use secret::SecretKey;
 pub use stark::{
     StarkSigner, OrderSigningParams,
-    sign_order, sign_order_with_params,
-    sign_transfer, sign_withdrawal,
+    sign_order, sign_order_with_params, sign_tpsl_triggers,
+    sign_transfer, sign_withdrawal, verify_signed_order,
     get_private_key_from_eth_signature,
 };
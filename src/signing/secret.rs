@@ -0,0 +1,61 @@
+//! Secret key wrapper with redacted output and constant-time comparison.
+
+use std::fmt;
+
+use starknet::core::types::Felt;
+
+/// A Stark private key.
+///
+/// Wraps the underlying `Felt` so it can't be printed by accident: `Debug` renders as
+/// `SecretKey(***)`. Equality compares all bytes without short-circuiting, so comparing
+/// keys does not leak how many leading bytes matched.
+#[derive(Clone)]
+pub struct SecretKey(Felt);
+
+impl SecretKey {
+    /// Wrap a private key.
+    pub fn new(key: Felt) -> Self {
+        Self(key)
+    }
+
+    /// Access the raw key. Avoid logging or formatting the returned value.
+    pub fn expose(&self) -> &Felt {
+        &self.0
+    }
+}
+
+impl From<Felt> for SecretKey {
+    fn from(key: Felt) -> Self {
+        Self::new(key)
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        let a = self.0.to_bytes_be();
+        let b = other.0.to_bytes_be();
+        let diff = a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+        std::hint::black_box(diff) == 0
+    }
+}
+
+impl Eq for SecretKey {}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretKey(***)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_key_redacted_and_comparable() {
+        let key = SecretKey::new(Felt::from_hex("0x1234").unwrap());
+        assert_eq!(format!("{:?}", key), "SecretKey(***)");
+        assert_eq!(key, SecretKey::new(Felt::from_hex("0x1234").unwrap()));
+        assert_ne!(key, SecretKey::new(Felt::from_hex("0x1235").unwrap()));
+    }
+}
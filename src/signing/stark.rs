@@ -4,26 +4,26 @@
 //! which provides cryptographically correct order hashing and ECDSA signing.
 
 use rust_crypto_lib_base::{get_order_hash, sign_message};
-use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
 use starknet::core::types::Felt;
 use starknet_crypto::get_public_key;
 
+use super::SecretKey;
 use crate::config::StarknetDomain;
 use crate::error::{ExtendedError, Result};
 use crate::models::{
-    CreateOrderRequest, OrderSide, SettlementSignature, StarkDebuggingOrderAmounts,
-    StarkSettlementModel, TransferRequest, TransferSignature, WithdrawalRequest,
-    WithdrawalSignature,
+    CollateralConfig, CreateOrderRequest, OrderSide, SettlementSignature,
+    StarkDebuggingOrderAmounts, StarkSettlementModel, TpslTrigger, TransferRequest,
+    TransferSignature, WithdrawalRequest, WithdrawalSignature,
 };
 
-/// Settlement resolution for collateral (USDC) - 10^6.
-const COLLATERAL_RESOLUTION: i64 = 1_000_000;
-
 /// Stark signer for creating signatures.
+///
+/// The private key is held as a [`SecretKey`], so `Debug` output never includes it.
 #[derive(Debug, Clone)]
 pub struct StarkSigner {
-    private_key: Felt,
+    private_key: SecretKey,
     public_key: Felt,
 }
 
@@ -34,7 +34,7 @@ impl StarkSigner {
         // Derive public key from private key using proper Stark curve
         let public_key = get_public_key(&private_key);
         Ok(Self {
-            private_key,
+            private_key: SecretKey::new(private_key),
             public_key,
         })
     }
@@ -43,7 +43,7 @@ impl StarkSigner {
     /// Use this when you have a registered public key that should be used for signing.
     pub fn with_public_key(private_key: Felt, public_key: Felt) -> Self {
         Self {
-            private_key,
+            private_key: SecretKey::new(private_key),
             public_key,
         }
     }
@@ -73,14 +73,14 @@ impl StarkSigner {
     /// Check if the stored public key matches the derived public key.
     /// Returns true if they match, false otherwise.
     pub fn verify_public_key(&self) -> bool {
-        let derived = get_public_key(&self.private_key);
+        let derived = get_public_key(self.private_key.expose());
         derived == self.public_key
     }
 
     /// Get the derived public key (from the private key).
     /// This may differ from the stored public key if `with_public_key` was used.
     pub fn derived_public_key(&self) -> Felt {
-        get_public_key(&self.private_key)
+        get_public_key(self.private_key.expose())
     }
 
     /// Get the derived public key as hex string.
@@ -100,20 +100,36 @@ impl StarkSigner {
 
     /// Get the private key.
     pub fn private_key(&self) -> &Felt {
+        self.private_key.expose()
+    }
+
+    /// Get the private key wrapped as a [`SecretKey`] (redacted `Debug`, constant-time `==`).
+    pub fn secret_key(&self) -> &SecretKey {
         &self.private_key
     }
 
     /// Get the private key as hex string.
+    ///
+    /// Prefer [`StarkSigner::secret_key`] for comparisons; the returned string is not
+    /// protected against logging.
     pub fn private_key_hex(&self) -> String {
-        format!("{:#x}", self.private_key)
+        format!("{:#x}", self.private_key.expose())
     }
 
     /// Sign a message hash.
     pub fn sign(&self, message_hash: &Felt) -> Result<(Felt, Felt)> {
-        let signature = sign_message(message_hash, &self.private_key)
+        let signature = sign_message(message_hash, self.private_key.expose())
             .map_err(|e| ExtendedError::Signing(format!("Failed to sign: {}", e)))?;
         Ok((signature.r, signature.s))
     }
+
+    /// Check that `(r, s)` is a valid signature of `message_hash` under this signer's
+    /// public key.
+    ///
+    /// Returns false for malformed signatures as well as mismatched ones.
+    pub fn verify(&self, message_hash: &Felt, r: &Felt, s: &Felt) -> bool {
+        starknet_crypto::verify(&self.public_key, message_hash, r, s).unwrap_or(false)
+    }
 }
 
 /// Parameters needed for signing an order.
@@ -127,31 +143,101 @@ pub struct OrderSigningParams {
     pub synthetic_resolution: i64,
     /// Collateral asset ID (quote asset settlement_external_id)
     pub collateral_asset_id: String,
+    /// Collateral asset resolution (10^decimals)
+    pub collateral_resolution: i64,
     /// Starknet domain for signing
     pub domain: StarknetDomain,
 }
 
+/// Multiply two decimals, failing if the product is not exactly representable.
+///
+/// `Decimal` holds at most 28 fractional digits in a 96-bit mantissa; beyond that
+/// multiplication silently rounds. The exact product is computed on the i128
+/// mantissas and compared with what a `Decimal` can hold, so both overflow and
+/// precision loss surface as a signing error naming the quantity involved.
+fn exact_mul(a: Decimal, b: Decimal, what: &str) -> Result<Decimal> {
+    let (a, b) = (a.normalize(), b.normalize());
+    let lost = || {
+        ExtendedError::Signing(format!(
+            "{} loses precision: {} * {} exceeds decimal range",
+            what, a, b
+        ))
+    };
+
+    let mut mantissa = a.mantissa().checked_mul(b.mantissa()).ok_or_else(lost)?;
+    let mut scale = a.scale() + b.scale();
+    while scale > 0 && mantissa % 10 == 0 {
+        mantissa /= 10;
+        scale -= 1;
+    }
+    Decimal::try_from_i128_with_scale(mantissa, scale).map_err(|_| lost())
+}
+
 /// Calculate Stark amounts from human-readable order values.
+///
+/// `side` and `price` are passed separately from the order so attached TP/SL triggers,
+/// which settle on the closing side at their own price, can reuse the calculation.
+///
+/// Every intermediate product is checked with [`exact_mul`], so large notionals on
+/// high-priced assets fail with a descriptive error instead of signing rounded amounts.
+///
+/// The quantity must be a whole number of synthetic stark units; a finer quantity fails
+/// with `ExtendedError::OrderValidation` rather than being rounded to a different size.
+/// A collateral amount finer than one stark unit is rounded never in the user's
+/// favour: up for a buy (away from zero, so it pays at least the notional) and down for
+/// a sell (towards zero, so it receives at most the notional). The fee always rounds
+/// up. Truncating instead would make buys at some prices sign a collateral amount one
+/// unit short of the notional.
 fn calculate_stark_amounts(
     order: &CreateOrderRequest,
+    side: OrderSide,
+    price: Decimal,
     params: &OrderSigningParams,
 ) -> Result<(i64, i64, u64)> {
+    // A zero price would sign a zero collateral amount, e.g. a market order whose
+    // slippage price was never set
+    if price <= Decimal::ZERO || order.quantity <= Decimal::ZERO {
+        return Err(ExtendedError::OrderValidation(format!(
+            "order price and quantity must be positive, got price {} and quantity {}",
+            price, order.quantity
+        )));
+    }
+    let collateral_resolution = Decimal::from(params.collateral_resolution);
+    let rounding = match side {
+        OrderSide::Buy => RoundingStrategy::AwayFromZero,
+        OrderSide::Sell => RoundingStrategy::ToZero,
+    };
+
     // Calculate synthetic amount in stark units
     let synthetic_amount_human = order.quantity;
-    let synthetic_amount_stark = (synthetic_amount_human * Decimal::from(params.synthetic_resolution))
+    let synthetic_units = exact_mul(
+        synthetic_amount_human,
+        Decimal::from(params.synthetic_resolution),
+        "Synthetic amount",
+    )?;
+    if !synthetic_units.fract().is_zero() {
+        return Err(ExtendedError::OrderValidation(format!(
+            "quantity {} has more decimal places than the market supports (smallest unit {})",
+            order.quantity,
+            (Decimal::ONE / Decimal::from(params.synthetic_resolution)).normalize()
+        )));
+    }
+    let synthetic_amount_stark = synthetic_units
         .to_i64()
         .ok_or_else(|| ExtendedError::Signing("Synthetic amount overflow".to_string()))?;
 
     // Calculate collateral amount in stark units (price * quantity)
-    let collateral_amount_human = order.price * order.quantity;
-    let collateral_amount_stark = (collateral_amount_human * Decimal::from(COLLATERAL_RESOLUTION))
-        .to_i64()
-        .ok_or_else(|| ExtendedError::Signing("Collateral amount overflow".to_string()))?;
+    let collateral_amount_human = exact_mul(price, order.quantity, "Order notional")?;
+    let collateral_amount_stark =
+        exact_mul(collateral_amount_human, collateral_resolution, "Collateral amount")?
+            .round_dp_with_strategy(0, rounding)
+            .to_i64()
+            .ok_or_else(|| ExtendedError::Signing("Collateral amount overflow".to_string()))?;
 
     // Calculate fee amount in stark units
     // Python SDK uses ROUND_UP for fees, so we use ceil() here
-    let fee_amount_human = order.fee * collateral_amount_human;
-    let fee_amount_stark = (fee_amount_human * Decimal::from(COLLATERAL_RESOLUTION))
+    let fee_amount_human = exact_mul(order.fee, collateral_amount_human, "Fee amount")?;
+    let fee_amount_stark = exact_mul(fee_amount_human, collateral_resolution, "Fee amount")?
         .abs()
         .ceil()
         .to_u64()
@@ -160,7 +246,7 @@ fn calculate_stark_amounts(
     // Adjust signs based on buy/sell
     // For BUY: synthetic is positive (receiving), collateral is negative (paying)
     // For SELL: synthetic is negative (paying), collateral is positive (receiving)
-    let (final_synthetic, final_collateral) = match order.side {
+    let (final_synthetic, final_collateral) = match side {
         OrderSide::Buy => (synthetic_amount_stark, -collateral_amount_stark),
         OrderSide::Sell => (-synthetic_amount_stark, collateral_amount_stark),
     };
@@ -168,6 +254,37 @@ fn calculate_stark_amounts(
     Ok((final_synthetic, final_collateral, fee_amount_stark))
 }
 
+/// Convert a transfer or withdrawal amount to collateral stark units.
+///
+/// Fails with `ExtendedError::InvalidParameter` unless the amount is positive, a whole
+/// number of the collateral's smallest unit (so nothing is truncated), and fits in a
+/// `u64` once scaled.
+fn collateral_units(amount: Decimal, collateral: &CollateralConfig) -> Result<u64> {
+    if amount <= Decimal::ZERO {
+        return Err(ExtendedError::InvalidParameter(format!(
+            "amount must be positive, got {}",
+            amount
+        )));
+    }
+    let too_large = || {
+        ExtendedError::InvalidParameter(format!(
+            "amount {} is too large to sign at collateral resolution {}",
+            amount, collateral.resolution
+        ))
+    };
+    let units = amount
+        .checked_mul(Decimal::from(collateral.resolution))
+        .ok_or_else(too_large)?;
+    if !units.fract().is_zero() {
+        return Err(ExtendedError::InvalidParameter(format!(
+            "amount {} has more decimal places than the collateral supports (smallest unit {})",
+            amount,
+            (Decimal::ONE / Decimal::from(collateral.resolution)).normalize()
+        )));
+    }
+    units.to_u64().ok_or_else(too_large)
+}
+
 /// Calculate expiration timestamp with buffer (14 days from order expiry).
 /// Uses ceiling division to match Python SDK's math.ceil() behavior.
 fn calculate_settlement_expiration(expiry_epoch_millis: i64) -> u64 {
@@ -180,24 +297,21 @@ fn calculate_settlement_expiration(expiry_epoch_millis: i64) -> u64 {
     (total_millis + 999) / 1000
 }
 
-/// Sign an order request with full parameters.
-///
-/// This function computes the proper Starknet order hash and creates a valid signature.
-///
-/// # Arguments
-/// * `order` - The order request to sign
-/// * `signer` - Stark signer
-/// * `params` - Order signing parameters (vault_id, asset IDs, domain)
+/// Compute the Starknet hash of one settlement leg of an order.
 ///
-/// # Returns
-/// The order with settlement data and ID set from the order hash
-pub fn sign_order_with_params(
-    mut order: CreateOrderRequest,
-    signer: &StarkSigner,
+/// Quantity, fee, nonce and expiry come from the order; `side` and `price` are the
+/// order's own for the main settlement, or the closing side and execution price for
+/// an attached TP/SL trigger.
+fn settlement_hash(
+    order: &CreateOrderRequest,
+    side: OrderSide,
+    price: Decimal,
+    public_key_hex: String,
     params: &OrderSigningParams,
-) -> Result<CreateOrderRequest> {
+) -> Result<(Felt, StarkDebuggingOrderAmounts)> {
     // Calculate stark amounts
-    let (synthetic_amount, collateral_amount, fee_amount) = calculate_stark_amounts(&order, params)?;
+    let (synthetic_amount, collateral_amount, fee_amount) =
+        calculate_stark_amounts(order, side, price, params)?;
 
     // Get nonce as u64
     let nonce = order.nonce.to_u64().unwrap_or(0);
@@ -206,7 +320,7 @@ pub fn sign_order_with_params(
     let expiration = calculate_settlement_expiration(order.expiry_epoch_millis);
 
     // Compute order hash using the proper Starknet message hashing
-    let order_hash = get_order_hash(
+    let hash = get_order_hash(
         params.vault_id.to_string(),
         params.synthetic_asset_id.clone(),
         synthetic_amount.to_string(),
@@ -216,7 +330,7 @@ pub fn sign_order_with_params(
         fee_amount.to_string(),
         expiration.to_string(),
         nonce.to_string(),
-        signer.public_key_hex(),
+        public_key_hex,
         params.domain.name.clone(),
         params.domain.version.clone(),
         params.domain.chain_id.clone(),
@@ -224,39 +338,158 @@ pub fn sign_order_with_params(
     )
     .map_err(|e| ExtendedError::Signing(format!("Failed to compute order hash: {}", e)))?;
 
-    // Sign the hash
-    let (r, s) = signer.sign(&order_hash)?;
-
-    // Set order ID to the hash (decimal string, matching Python SDK's str(order_hash))
-    // Convert Felt to decimal string via BigUint
-    let hash_bytes = order_hash.to_bytes_be();
-    let hash_bigint = num_bigint::BigUint::from_bytes_be(&hash_bytes);
-    order.id = hash_bigint.to_string();
+    // Debugging amounts (optional but helpful)
+    let amounts = StarkDebuggingOrderAmounts {
+        synthetic_amount: Decimal::from(synthetic_amount),
+        collateral_amount: Decimal::from(collateral_amount),
+        fee_amount: Decimal::from(fee_amount as i64),
+    };
+    Ok((hash, amounts))
+}
 
-    // Create settlement with signature
-    order.settlement = Some(StarkSettlementModel {
+/// Hash and sign one settlement leg of an order (see [`settlement_hash`]).
+fn sign_settlement(
+    order: &CreateOrderRequest,
+    side: OrderSide,
+    price: Decimal,
+    signer: &StarkSigner,
+    params: &OrderSigningParams,
+) -> Result<(Felt, StarkSettlementModel, StarkDebuggingOrderAmounts)> {
+    let (hash, amounts) = settlement_hash(order, side, price, signer.public_key_hex(), params)?;
+    let (r, s) = signer.sign(&hash)?;
+    let settlement = StarkSettlementModel {
         signature: SettlementSignature {
             r: format!("{:#x}", r),
             s: format!("{:#x}", s),
         },
         stark_key: signer.public_key_hex(),
         collateral_position: Decimal::from(params.vault_id),
-    });
+    };
+    Ok((hash, settlement, amounts))
+}
 
-    // Add debugging amounts (optional but helpful)
-    order.debugging_amounts = Some(StarkDebuggingOrderAmounts {
-        synthetic_amount: Decimal::from(synthetic_amount),
-        collateral_amount: Decimal::from(collateral_amount),
-        fee_amount: Decimal::from(fee_amount as i64),
-    });
+/// Sign an order request with full parameters.
+///
+/// This function computes the proper Starknet order hash and creates a valid signature.
+/// Attached take-profit and stop-loss triggers are signed too (see [`sign_tpsl_triggers`]).
+///
+/// # Arguments
+/// * `order` - The order request to sign
+/// * `signer` - Stark signer
+/// * `params` - Order signing parameters (vault_id, asset IDs, domain)
+///
+/// # Returns
+/// The order with settlement data attached. The ID is set from the order hash
+/// unless an external ID was provided via `OrderBuilder::external_id`.
+///
+/// For conditional orders the hash covers the execution amounts (order price and
+/// quantity), as the exchange expects; the `trigger` itself is not part of the hash.
+pub fn sign_order_with_params(
+    mut order: CreateOrderRequest,
+    signer: &StarkSigner,
+    params: &OrderSigningParams,
+) -> Result<CreateOrderRequest> {
+    let (order_hash, settlement, amounts) =
+        sign_settlement(&order, order.side, order.price, signer, params)?;
+
+    // Set order ID to the hash (decimal string, matching Python SDK's str(order_hash)),
+    // unless the caller provided an external ID (the builder uses the nonce as a placeholder)
+    let nonce = order.nonce.to_u64().unwrap_or(0);
+    if order.id.is_empty() || order.id == nonce.to_string() {
+        // Convert Felt to decimal string via BigUint
+        let hash_bytes = order_hash.to_bytes_be();
+        let hash_bigint = num_bigint::BigUint::from_bytes_be(&hash_bytes);
+        order.id = hash_bigint.to_string();
+    }
+
+    order.settlement = Some(settlement);
+    order.debugging_amounts = Some(amounts);
+
+    sign_tpsl_triggers(order, signer, params)
+}
 
+/// Sign the settlements of an order's attached take-profit and stop-loss triggers.
+///
+/// Each trigger settles as an order on the closing side (opposite to the order's side)
+/// for the order's quantity at the trigger's execution `price`, sharing the order's
+/// fee, nonce and expiry. Orders without triggers are returned unchanged.
+/// `sign_order_with_params` calls this, so it's only needed when re-signing triggers
+/// that were changed after the order was signed.
+pub fn sign_tpsl_triggers(
+    mut order: CreateOrderRequest,
+    signer: &StarkSigner,
+    params: &OrderSigningParams,
+) -> Result<CreateOrderRequest> {
+    let closing_side = order.side.opposite();
+    let sign = |trigger: Option<TpslTrigger>| -> Result<Option<TpslTrigger>> {
+        let Some(mut trigger) = trigger else {
+            return Ok(None);
+        };
+        let (_, settlement, amounts) =
+            sign_settlement(&order, closing_side, trigger.price, signer, params)?;
+        trigger.settlement = Some(settlement);
+        trigger.debugging_amounts = Some(amounts);
+        Ok(Some(trigger))
+    };
+
+    let take_profit = sign(order.take_profit.clone())?;
+    let stop_loss = sign(order.stop_loss.clone())?;
+    order.take_profit = take_profit;
+    order.stop_loss = stop_loss;
     Ok(order)
 }
 
+/// Check a signed order's settlement signatures before submitting it.
+///
+/// Recomputes the order hash from the order's fields and `params`, and verifies the
+/// settlement signature against the settlement's `stark_key`; attached TP/SL triggers
+/// are checked the same way. Returns `Ok(false)` if any signature doesn't match, e.g.
+/// because the order was changed after signing or the signer's public key (see
+/// `StarkSigner::with_public_key`) doesn't belong to its private key.
+///
+/// # Arguments
+/// * `order` - The signed order request
+/// * `params` - The parameters the order was signed with
+///
+/// # Errors
+/// `ExtendedError::Signing` if the order (or a trigger) has no settlement or its
+/// signature fields aren't valid hex.
+pub fn verify_signed_order(
+    order: &CreateOrderRequest,
+    params: &OrderSigningParams,
+) -> Result<bool> {
+    let verify = |settlement: Option<&StarkSettlementModel>, side: OrderSide, price: Decimal| {
+        let settlement = settlement.ok_or_else(|| {
+            ExtendedError::Signing("Order has no settlement to verify".to_string())
+        })?;
+        let parse = |hex: &str, what: &str| {
+            Felt::from_hex(hex)
+                .map_err(|e| ExtendedError::Signing(format!("Invalid {} hex: {:?}", what, e)))
+        };
+        let stark_key = parse(&settlement.stark_key, "stark key")?;
+        let r = parse(&settlement.signature.r, "signature r")?;
+        let s = parse(&settlement.signature.s, "signature s")?;
+
+        let (hash, _) = settlement_hash(order, side, price, settlement.stark_key.clone(), params)?;
+        Ok::<_, ExtendedError>(starknet_crypto::verify(&stark_key, &hash, &r, &s).unwrap_or(false))
+    };
+
+    if !verify(order.settlement.as_ref(), order.side, order.price)? {
+        return Ok(false);
+    }
+    for trigger in [order.take_profit.as_ref(), order.stop_loss.as_ref()].into_iter().flatten() {
+        if !verify(trigger.settlement.as_ref(), order.side.opposite(), trigger.price)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
 /// Simplified sign_order for backwards compatibility.
 ///
-/// Note: This version uses default asset IDs. For production use with specific markets,
-/// use `sign_order_with_params` with the correct asset settlement IDs from the market data.
+/// Note: This version uses default collateral settings (`CollateralConfig::default`). For
+/// production use with specific markets, use `sign_order_with_params` with the correct asset
+/// settlement IDs from the market data.
 ///
 /// # Arguments
 /// * `order` - The order request to sign
@@ -280,11 +513,13 @@ pub fn sign_order(
         .parse()
         .map_err(|e| ExtendedError::Signing(format!("Invalid vault ID: {}", e)))?;
 
+    let collateral = CollateralConfig::default();
     let params = OrderSigningParams {
         vault_id: vault_id_u32,
         synthetic_asset_id: synthetic_asset_id.to_string(),
         synthetic_resolution,
-        collateral_asset_id: "0x1".to_string(), // Default USDC
+        collateral_asset_id: collateral.asset_id,
+        collateral_resolution: collateral.resolution,
         domain: domain.clone(),
     };
 
@@ -301,13 +536,16 @@ pub fn get_private_key_from_eth_signature(signature: &str) -> Result<Felt> {
 }
 
 /// Sign a withdrawal request.
+///
+/// `collateral` comes from `PublicApi::get_collateral_config` (or
+/// `Market::collateral_config`); its resolution converts `amount` to stark units.
 pub fn sign_withdrawal(
     amount: Decimal,
     recipient: &str,
     nonce: u64,
     expiry_millis: i64,
     vault_id: &str,
-    collateral_asset_id: &str,
+    collateral: &CollateralConfig,
     signer: &StarkSigner,
     domain: &StarknetDomain,
 ) -> Result<WithdrawalRequest> {
@@ -315,16 +553,14 @@ pub fn sign_withdrawal(
         .parse()
         .map_err(|e| ExtendedError::Signing(format!("Invalid vault ID: {}", e)))?;
 
-    let amount_stark = (amount * Decimal::from(COLLATERAL_RESOLUTION))
-        .to_u64()
-        .ok_or_else(|| ExtendedError::Signing("Amount overflow".to_string()))?;
+    let amount_stark = collateral_units(amount, collateral)?;
 
     let expiration = calculate_settlement_expiration(expiry_millis);
 
     let hash = rust_crypto_lib_base::get_withdrawal_hash(
         recipient.to_string(),
         vault_id_u32.to_string(),
-        collateral_asset_id.to_string(),
+        collateral.asset_id.clone(),
         amount_stark.to_string(),
         expiration.to_string(),
         nonce.to_string(),
@@ -351,26 +587,27 @@ pub fn sign_withdrawal(
 }
 
 /// Sign a transfer request.
+///
+/// `collateral` comes from `PublicApi::get_collateral_config` (or
+/// `Market::collateral_config`); its resolution converts `amount` to stark units.
 pub fn sign_transfer(
     amount: Decimal,
     recipient_vault_id: &str,
     sender_vault_id: &str,
     nonce: u64,
     expiry_millis: i64,
-    collateral_asset_id: &str,
+    collateral: &CollateralConfig,
     signer: &StarkSigner,
     domain: &StarknetDomain,
 ) -> Result<TransferRequest> {
-    let amount_stark = (amount * Decimal::from(COLLATERAL_RESOLUTION))
-        .to_u64()
-        .ok_or_else(|| ExtendedError::Signing("Amount overflow".to_string()))?;
+    let amount_stark = collateral_units(amount, collateral)?;
 
     let expiration = calculate_settlement_expiration(expiry_millis);
 
     let hash = rust_crypto_lib_base::get_transfer_hash(
         recipient_vault_id.to_string(),
         sender_vault_id.to_string(),
-        collateral_asset_id.to_string(),
+        collateral.asset_id.clone(),
         amount_stark.to_string(),
         expiration.to_string(),
         nonce.to_string(),
@@ -400,6 +637,24 @@ pub fn sign_transfer(
 mod tests {
     use super::*;
 
+    /// Signer for a fixed test key (not a real account).
+    fn test_signer() -> StarkSigner {
+        StarkSigner::from_hex("0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef")
+            .unwrap()
+    }
+
+    /// Signing parameters for a BTC-USD-like market on testnet.
+    fn test_params() -> OrderSigningParams {
+        OrderSigningParams {
+            vault_id: 10001,
+            synthetic_asset_id: "0x4254432d3600000000000000000000".to_string(),
+            synthetic_resolution: 1_000_000,
+            collateral_asset_id: "0x1".to_string(),
+            collateral_resolution: 1_000_000,
+            domain: crate::config::testnet_config().starknet_domain,
+        }
+    }
+
     #[test]
     fn test_stark_signer_from_hex() {
         // Use a valid Stark private key
@@ -408,10 +663,260 @@ mod tests {
         assert!(!signer.public_key().eq(&Felt::ZERO));
     }
 
+    #[test]
+    fn test_stark_signer_debug_redacts_private_key() {
+        let hex_key = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let signer = StarkSigner::from_hex(hex_key).unwrap();
+        let debug = format!("{:?}", signer);
+        assert!(debug.contains("SecretKey(***)"));
+        assert!(!debug.contains(&signer.private_key_hex()));
+    }
+
     #[test]
     fn test_get_private_key_from_eth_signature() {
         let signature = "0x9ef64d5936681edf44b4a7ad713f3bc24065d4039562af03fccf6a08d6996eab367df11439169b417b6a6d8ce81d409edb022597ce193916757c7d5d9cbf97301c";
         let result = get_private_key_from_eth_signature(signature);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_exact_mul_detects_precision_loss() {
+        use std::str::FromStr;
+
+        let price = Decimal::from_str("3350.25").unwrap();
+        let qty = Decimal::from_str("0.0100").unwrap();
+        assert_eq!(
+            exact_mul(price, qty, "Order notional").unwrap(),
+            Decimal::from_str("33.5025").unwrap()
+        );
+
+        let price = Decimal::from_str("98765432.123456789012345").unwrap();
+        let qty = Decimal::from_str("12345.678901234567").unwrap();
+        assert!(matches!(
+            exact_mul(price, qty, "Order notional"),
+            Err(ExtendedError::Signing(_))
+        ));
+
+        // Overflow of the 96-bit mantissa is reported the same way.
+        assert!(exact_mul(Decimal::MAX, Decimal::from(2), "Collateral amount").is_err());
+    }
+
+    #[test]
+    fn test_transfer_amount_validation() {
+        use rust_decimal_macros::dec;
+
+        let signer = test_signer();
+        let collateral = CollateralConfig::default();
+        let domain = crate::config::testnet_config().starknet_domain;
+        let expiry = 1_700_000_000_000;
+        let transfer = |amount: Decimal| {
+            sign_transfer(amount, "20002", "10001", 1, expiry, &collateral, &signer, &domain)
+        };
+        let withdrawal = |amount: Decimal| {
+            sign_withdrawal(amount, "0x0456", 1, expiry, "10001", &collateral, &signer, &domain)
+        };
+
+        assert_eq!(collateral_units(dec!(12.345678), &collateral).unwrap(), 12_345_678);
+        assert!(transfer(dec!(12.345678)).is_ok());
+        assert!(withdrawal(dec!(0.000001)).is_ok());
+
+        for (amount, expected) in [
+            (dec!(-5), "must be positive"),
+            (Decimal::ZERO, "must be positive"),
+            (dec!(1.0000001), "smallest unit 0.000001"),
+            (dec!(20000000000000), "too large"),
+        ] {
+            for result in [transfer(amount).map(|_| ()), withdrawal(amount).map(|_| ())] {
+                match result {
+                    Err(ExtendedError::InvalidParameter(message)) => {
+                        assert!(message.contains(expected), "{}", message)
+                    }
+                    other => panic!("{} gave {:?}", amount, other),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fractional_collateral_amounts_round_against_the_user() {
+        use crate::models::OrderBuilder;
+        use rust_decimal_macros::dec;
+
+        let params = test_params();
+        let fee = dec!(0.0005);
+        let amounts = |side: OrderSide, price: Decimal, quantity: Decimal| {
+            let order = OrderBuilder::limit("BTC-USD", side, price, quantity, false, false)
+                .fee(fee)
+                .nonce(1)
+                .build();
+            calculate_stark_amounts(&order, side, price, &params)
+        };
+        // Exact value of `a * b * resolution` as an integer fraction, floored and ceiled
+        let bounds = |factors: &[Decimal]| {
+            let (num, den) = factors.iter().fold((1_000_000i128, 1i128), |(num, den), d| {
+                (num * d.mantissa(), den * 10i128.pow(d.scale()))
+            });
+            (num.div_euclid(den), num.div_euclid(den) + (num % den != 0) as i128)
+        };
+
+        let cases = [
+            (dec!(0.0012345), dec!(7)),
+            (dec!(0.33000003), dec!(10.000001)),
+            (dec!(95000.7), dec!(0.000013)),
+            (dec!(1.999999), dec!(0.333333)),
+            (dec!(95000), dec!(0.01)),
+        ];
+        for (price, quantity) in cases {
+            let (floor, ceil) = bounds(&[price, quantity]);
+            let (_, fee_ceil) = bounds(&[price, quantity, fee]);
+            let synthetic = (quantity * dec!(1_000_000)).to_i64().unwrap();
+
+            let buy = amounts(OrderSide::Buy, price, quantity).unwrap();
+            let fee_units = fee_ceil as u64;
+            assert_eq!(buy, (synthetic, -ceil as i64, fee_units), "buy {price} x {quantity}");
+            let sell = amounts(OrderSide::Sell, price, quantity).unwrap();
+            assert_eq!(sell, (-synthetic, floor as i64, fee_units), "sell {price} x {quantity}");
+        }
+
+        // A quantity finer than the synthetic resolution isn't silently resized
+        for side in [OrderSide::Buy, OrderSide::Sell] {
+            let result = amounts(side, dec!(0.33000003), dec!(10.0000001));
+            assert!(matches!(result, Err(ExtendedError::OrderValidation(_))), "{:?}", result);
+        }
+    }
+
+    #[test]
+    fn test_unpriced_orders_are_rejected() {
+        use crate::models::OrderBuilder;
+        use rust_decimal_macros::dec;
+
+        let (signer, params) = (test_signer(), test_params());
+        let unpriced = OrderBuilder::market("BTC-USD", OrderSide::Buy, dec!(0.01), Decimal::ZERO);
+        for result in [
+            unpriced.clone().build_and_sign(&signer, &params),
+            sign_order_with_params(unpriced.slippage_price(dec!(-1)).build(), &signer, &params),
+            OrderBuilder::limit("BTC-USD", OrderSide::Sell, dec!(95000), dec!(0), false, false)
+                .build_and_sign(&signer, &params),
+        ] {
+            assert!(matches!(result, Err(ExtendedError::OrderValidation(_))), "{:?}", result);
+        }
+
+        let priced = OrderBuilder::market("BTC-USD", OrderSide::Buy, dec!(0.01), dec!(101000));
+        assert!(priced.build_and_sign(&signer, &params).is_ok());
+    }
+
+    #[test]
+    fn test_verify_signed_order() {
+        use crate::models::{OrderBuilder, OrderPriceType};
+        use rust_decimal_macros::dec;
+
+        let signer = test_signer();
+        let params = test_params();
+        let order =
+            OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000), dec!(0.01), false, false)
+                .stop_loss(dec!(90000), dec!(89000), OrderPriceType::Market)
+                .nonce(8)
+                .expiry(1_700_000_000_000)
+                .build();
+        assert!(verify_signed_order(&order, &params).is_err());
+
+        let signed = sign_order_with_params(order, &signer, &params).unwrap();
+        assert!(verify_signed_order(&signed, &params).unwrap());
+
+        let settlement = signed.settlement.as_ref().unwrap();
+        let r = Felt::from_hex(&settlement.signature.r).unwrap();
+        let s = Felt::from_hex(&settlement.signature.s).unwrap();
+        let (hash, _) =
+            settlement_hash(&signed, OrderSide::Buy, signed.price, signer.public_key_hex(), &params)
+                .unwrap();
+        assert!(signer.verify(&hash, &r, &s));
+        assert!(!signer.verify(&hash, &s, &r));
+
+        // Changing an amount after signing invalidates the signature
+        let mut tampered = signed.clone();
+        tampered.quantity = dec!(0.02);
+        assert!(!verify_signed_order(&tampered, &params).unwrap());
+
+        let mut tampered = signed.clone();
+        tampered.stop_loss.as_mut().unwrap().price = dec!(88000);
+        assert!(!verify_signed_order(&tampered, &params).unwrap());
+
+        // A public key that doesn't belong to the private key is caught too
+        let wrong_key = Felt::from_hex("0x12345").unwrap();
+        let mismatched = StarkSigner::with_public_key(*signer.private_key(), wrong_key);
+        let signed = sign_order_with_params(signed, &mismatched, &params).unwrap();
+        assert!(!verify_signed_order(&signed, &params).unwrap());
+    }
+
+    #[test]
+    fn test_position_tpsl_triggers_are_signed() {
+        use crate::models::{OrderBuilder, OrderPriceType, TpslType};
+        use rust_decimal_macros::dec;
+
+        let signer = test_signer();
+        let params = test_params();
+
+        let order =
+            OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000), dec!(0.01), false, false)
+                .take_profit(dec!(100000), dec!(99900), OrderPriceType::Limit)
+                .stop_loss(dec!(90000), dec!(89000), OrderPriceType::Market)
+                .tpsl_type(TpslType::Position)
+                .nonce(7)
+                .expiry(1_700_000_000_000)
+                .build();
+        let signed = sign_order_with_params(order, &signer, &params).unwrap();
+        assert_eq!(signed.tp_sl_type, Some(TpslType::Position));
+
+        for trigger in [signed.take_profit.as_ref(), signed.stop_loss.as_ref()] {
+            let trigger = trigger.unwrap();
+            let settlement = trigger.settlement.as_ref().unwrap();
+            assert_eq!(settlement.stark_key, signer.public_key_hex());
+
+            // Closing leg: sells the order's quantity at the trigger's execution price
+            let (hash, amounts) = settlement_hash(
+                &signed,
+                OrderSide::Sell,
+                trigger.price,
+                signer.public_key_hex(),
+                &params,
+            )
+            .unwrap();
+            assert_eq!(amounts.synthetic_amount, dec!(-10000));
+            assert_eq!(
+                amounts.collateral_amount,
+                trigger.price * dec!(0.01) * Decimal::from(params.collateral_resolution)
+            );
+
+            let r = Felt::from_hex(&settlement.signature.r).unwrap();
+            let s = Felt::from_hex(&settlement.signature.s).unwrap();
+            assert!(starknet_crypto::verify(signer.public_key(), &hash, &r, &s).unwrap());
+        }
+        assert_ne!(
+            signed.take_profit.unwrap().settlement.unwrap().signature.r,
+            signed.stop_loss.unwrap().settlement.unwrap().signature.r
+        );
+    }
+
+    #[test]
+    fn test_build_and_sign() {
+        use crate::models::OrderBuilder;
+        use rust_decimal_macros::dec;
+
+        let signer = test_signer();
+        let params = test_params();
+
+        let signed =
+            OrderBuilder::limit("BTC-USD", OrderSide::Sell, dec!(95000), dec!(0.01), false, false)
+                .build_and_sign(&signer, &params)
+                .unwrap();
+        assert!(signed.settlement.is_some());
+        assert!(verify_signed_order(&signed, &params).unwrap());
+
+        // The ID is the order hash in decimal
+        let public_key = signer.public_key_hex();
+        let (hash, _) =
+            settlement_hash(&signed, OrderSide::Sell, signed.price, public_key, &params).unwrap();
+        let hash = num_bigint::BigUint::from_bytes_be(&hash.to_bytes_be());
+        assert_eq!(signed.id, hash.to_string());
+    }
 }
@@ -0,0 +1,211 @@
+//! Candle stream.
+
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::Stream;
+use tokio::sync::mpsc;
+
+use super::connection::{self, CHANNEL_CAPACITY};
+use super::public::parse_envelope;
+use super::StreamHealth;
+use crate::config::EndpointConfig;
+use crate::error::Result;
+use crate::models::{Candle, CandleType, TimeInterval};
+
+/// A candle from the candle stream.
+#[derive(Debug, Clone)]
+pub struct CandleUpdate {
+    /// The candle as of this update.
+    pub candle: Candle,
+    /// Whether the candle's bucket has closed.
+    ///
+    /// An in-progress candle is yielded again each time it changes; a closed one is
+    /// final and is yielded once.
+    pub closed: bool,
+}
+
+/// Tracks the forming candle to tell in-progress updates from closed candles.
+///
+/// The channel only sends the current candle, so one closes when a candle with a later
+/// open time arrives.
+#[derive(Debug, Default)]
+struct CandleTracker {
+    current: Option<Candle>,
+}
+
+impl CandleTracker {
+    fn decode(&mut self, text: &str) -> Result<Vec<CandleUpdate>> {
+        let mut candles = parse_envelope::<Vec<Candle>>(text)?.data;
+        candles.sort_by_key(|candle| candle.timestamp);
+
+        let mut updates = Vec::new();
+        let mut changed = false;
+        for candle in candles {
+            match self.current.take() {
+                // A late update for a bucket that has already closed
+                Some(current) if candle.timestamp < current.timestamp => {
+                    self.current = Some(current);
+                    continue;
+                }
+                Some(current) if candle.timestamp > current.timestamp => {
+                    updates.push(CandleUpdate {
+                        candle: current,
+                        closed: true,
+                    });
+                }
+                _ => {}
+            }
+            self.current = Some(candle);
+            changed = true;
+        }
+
+        if let Some(current) = self.current.as_ref().filter(|_| changed) {
+            updates.push(CandleUpdate {
+                candle: current.clone(),
+                closed: false,
+            });
+        }
+        Ok(updates)
+    }
+}
+
+/// Stream of live candles for a market.
+///
+/// Yields a [`CandleUpdate`] each time the forming candle changes, and once more with
+/// `closed` set when the next bucket opens, so charts and indicators can follow the
+/// market without polling `get_candles`. The last candle is never marked closed if the
+/// stream ends before the next bucket opens.
+///
+/// # Example
+/// ```no_run
+/// # async fn example() -> extended_rust_sdk::error::Result<()> {
+/// use extended_rust_sdk::config::mainnet_config;
+/// use extended_rust_sdk::models::{CandleType, TimeInterval};
+/// use extended_rust_sdk::stream::CandleStream;
+/// use futures::StreamExt;
+///
+/// let config = mainnet_config();
+/// let mut candles =
+///     CandleStream::connect(&config, "BTC-USD", CandleType::Trades, TimeInterval::OneMinute)
+///         .await?;
+/// while let Some(update) = candles.next().await {
+///     let update = update?;
+///     if update.closed {
+///         println!("closed at {}", update.candle.close);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct CandleStream {
+    rx: mpsc::Receiver<Result<CandleUpdate>>,
+    health: StreamHealth,
+}
+
+impl CandleStream {
+    /// Connect to the candle channel of a market.
+    ///
+    /// # Arguments
+    /// * `config` - Endpoint configuration (uses `stream_base_url`)
+    /// * `market` - Market name (e.g., "BTC-USD")
+    /// * `candle_type` - Type of candle (trades, mark, or index)
+    /// * `interval` - Candle interval
+    pub async fn connect(
+        config: &EndpointConfig,
+        market: impl AsRef<str>,
+        candle_type: CandleType,
+        interval: TimeInterval,
+    ) -> Result<Self> {
+        let path = format!(
+            "candles/{}/{}?interval={}",
+            market.as_ref(),
+            candle_type.as_str(),
+            interval.as_str()
+        );
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        let health = StreamHealth::default();
+
+        let ws = connection::connect(&config.stream_url(&path)).await?;
+        let tracker = Mutex::new(CandleTracker::default());
+        connection::spawn_reader(ws, health.clone(), tx, move |text| {
+            tracker.lock().unwrap_or_else(|e| e.into_inner()).decode(text)
+        });
+
+        Ok(Self { rx, health })
+    }
+
+    /// Get the time elapsed since the last message.
+    pub fn last_message_age(&self) -> Duration {
+        self.health.last_message_age()
+    }
+
+    /// Check if a message was received within the keepalive window.
+    pub fn is_healthy(&self) -> bool {
+        self.health.is_healthy()
+    }
+}
+
+impl Stream for CandleStream {
+    type Item = Result<CandleUpdate>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn message(candles: &[(i64, &str)]) -> String {
+        let data: Vec<_> = candles
+            .iter()
+            .map(|(timestamp, close)| {
+                serde_json::json!({
+                    "timestamp": timestamp, "open": "100", "high": "110", "low": "90",
+                    "close": close, "volume": "5"
+                })
+            })
+            .collect();
+        serde_json::json!({ "data": data }).to_string()
+    }
+
+    fn summary(updates: Vec<CandleUpdate>) -> Vec<(i64, Decimal, bool)> {
+        updates
+            .into_iter()
+            .map(|u| (u.candle.timestamp, u.candle.close, u.closed))
+            .collect()
+    }
+
+    #[test]
+    fn test_candle_updates_close_when_the_next_bucket_opens() {
+        let mut tracker = CandleTracker::default();
+        let mut decode =
+            |candles: &[(i64, &str)]| summary(tracker.decode(&message(candles)).unwrap());
+
+        assert_eq!(decode(&[(60_000, "101")]), [(60_000, dec!(101), false)]);
+        assert_eq!(decode(&[(60_000, "102")]), [(60_000, dec!(102), false)]);
+        assert_eq!(
+            decode(&[(120_000, "103")]),
+            [(60_000, dec!(102), true), (120_000, dec!(103), false)]
+        );
+        // Late update for the closed bucket
+        assert!(decode(&[(60_000, "99")]).is_empty());
+        // Several buckets at once (e.g. after a quiet spell), out of order
+        assert_eq!(
+            decode(&[(240_000, "105"), (180_000, "104")]),
+            [
+                (120_000, dec!(103), true),
+                (180_000, dec!(104), true),
+                (240_000, dec!(105), false)
+            ]
+        );
+
+        assert!(tracker.decode("not json").is_err());
+    }
+}
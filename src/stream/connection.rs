@@ -0,0 +1,202 @@
+//! Shared WebSocket plumbing used by the stream types.
+
+use futures::StreamExt;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::{HeaderValue, StatusCode};
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+use super::StreamHealth;
+use crate::config::RetryConfig;
+use crate::error::{ExtendedError, Result};
+
+/// Capacity of the channel between connection tasks and the consumer.
+pub(crate) const CHANNEL_CAPACITY: usize = 1024;
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Open a WebSocket connection.
+pub(crate) async fn connect(url: &str) -> Result<WsStream> {
+    let (ws, _) = connect_async(url).await.map_err(|e| connect_error(url, e))?;
+    Ok(ws)
+}
+
+/// Open a WebSocket connection authenticated with the `X-Api-Key` header.
+///
+/// A rejected key (401/403 on the upgrade) fails with `ExtendedError::Authentication`.
+pub(crate) async fn connect_with_api_key(url: &str, api_key: &str) -> Result<WsStream> {
+    let mut request = url.into_client_request().map_err(|e| connect_error(url, e))?;
+    let api_key = HeaderValue::from_str(api_key)
+        .map_err(|_| ExtendedError::InvalidParameter("API key is not a valid header".into()))?;
+    request.headers_mut().insert("X-Api-Key", api_key);
+
+    let (ws, _) = connect_async(request).await.map_err(|e| connect_error(url, e))?;
+    Ok(ws)
+}
+
+fn connect_error(url: &str, e: tungstenite::Error) -> ExtendedError {
+    match e {
+        tungstenite::Error::Http(response)
+            if matches!(response.status(), StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) =>
+        {
+            ExtendedError::Authentication(format!(
+                "stream connection to {} rejected: {}",
+                url,
+                response.status()
+            ))
+        }
+        e => ExtendedError::WebSocket(format!("failed to connect to {}: {}", url, e)),
+    }
+}
+
+/// Why a connection stopped being read.
+enum ReadOutcome {
+    /// The consumer dropped the stream.
+    ReceiverDropped,
+    /// The server closed the connection normally.
+    Closed,
+    /// Transport error or abnormal close.
+    Failed(ExtendedError),
+}
+
+/// Read `ws` until it ends, decoding text frames and forwarding items to `tx`.
+async fn read_frames<T, F>(
+    ws: &mut WsStream,
+    health: &StreamHealth,
+    tx: &mpsc::Sender<Result<T>>,
+    decode: &F,
+) -> ReadOutcome
+where
+    F: Fn(&str) -> Result<Vec<T>>,
+{
+    loop {
+        let msg = tokio::select! {
+            _ = tx.closed() => return ReadOutcome::ReceiverDropped,
+            msg = ws.next() => msg,
+        };
+
+        let items = match msg {
+            Some(Ok(Message::Text(text))) => {
+                health.record_message();
+                match decode(text.as_str()) {
+                    Ok(items) => items.into_iter().map(Ok).collect(),
+                    Err(e) => vec![Err(e)],
+                }
+            }
+            Some(Ok(Message::Close(Some(frame)))) if frame.code != CloseCode::Normal => {
+                let message =
+                    format!("connection closed by server ({}): {}", frame.code, frame.reason);
+                return ReadOutcome::Failed(match frame.code {
+                    CloseCode::Policy => ExtendedError::Authentication(message),
+                    _ => ExtendedError::WebSocket(message),
+                });
+            }
+            Some(Ok(Message::Close(_))) | None => return ReadOutcome::Closed,
+            Some(Ok(_)) => {
+                // Pings, pongs and binary frames only count towards liveness.
+                health.record_message();
+                continue;
+            }
+            Some(Err(e)) => return ReadOutcome::Failed(ExtendedError::WebSocket(e.to_string())),
+        };
+
+        for item in items {
+            if tx.send(item).await.is_err() {
+                return ReadOutcome::ReceiverDropped;
+            }
+        }
+    }
+}
+
+/// Spawn a task that reads `ws`, decodes text frames and forwards items to `tx`.
+///
+/// The task ends when the server closes the connection, on a transport error (which is
+/// forwarded first), or when the receiving side is dropped — in which case a close
+/// frame is sent so the server sees a clean shutdown. A close frame with a code other
+/// than normal is forwarded as an error too (`Authentication` for a policy close, which
+/// is how a rejected subscription is reported), so disconnects are never silent.
+pub(crate) fn spawn_reader<T, F>(
+    mut ws: WsStream,
+    health: StreamHealth,
+    tx: mpsc::Sender<Result<T>>,
+    decode: F,
+) where
+    T: Send + 'static,
+    F: Fn(&str) -> Result<Vec<T>> + Send + Sync + 'static,
+{
+    tokio::spawn(async move {
+        match read_frames(&mut ws, &health, &tx, &decode).await {
+            ReadOutcome::ReceiverDropped => {
+                let _ = ws.close(None).await;
+            }
+            ReadOutcome::Closed => {}
+            ReadOutcome::Failed(e) => {
+                let _ = tx.send(Err(e)).await;
+            }
+        }
+    });
+}
+
+/// Like [`spawn_reader`], but reconnects to `url` whenever the connection drops.
+///
+/// Reconnection attempts back off according to `retry`; after a successful reconnect
+/// `reconnected()` is forwarded so the consumer knows to resync. The task ends with an
+/// error once `retry.max_attempts` consecutive attempts fail, or straight away on an
+/// authentication rejection, since retrying can't fix that.
+pub(crate) fn spawn_reconnecting_reader<T, F, R>(
+    mut ws: WsStream,
+    url: String,
+    retry: RetryConfig,
+    health: StreamHealth,
+    tx: mpsc::Sender<Result<T>>,
+    decode: F,
+    reconnected: R,
+) where
+    T: Send + 'static,
+    F: Fn(&str) -> Result<Vec<T>> + Send + Sync + 'static,
+    R: Fn() -> T + Send + 'static,
+{
+    tokio::spawn(async move {
+        loop {
+            match read_frames(&mut ws, &health, &tx, &decode).await {
+                ReadOutcome::ReceiverDropped => {
+                    let _ = ws.close(None).await;
+                    return;
+                }
+                ReadOutcome::Failed(e @ ExtendedError::Authentication(_)) => {
+                    let _ = tx.send(Err(e)).await;
+                    return;
+                }
+                ReadOutcome::Closed | ReadOutcome::Failed(_) => {}
+            }
+
+            let mut attempt = 1;
+            ws = loop {
+                tokio::select! {
+                    _ = tx.closed() => return,
+                    _ = tokio::time::sleep(retry.backoff(attempt)) => {}
+                }
+                match connect(&url).await {
+                    Ok(ws) => break ws,
+                    Err(e @ ExtendedError::Authentication(_)) => {
+                        let _ = tx.send(Err(e)).await;
+                        return;
+                    }
+                    Err(e) if attempt >= retry.max_attempts => {
+                        let _ = tx.send(Err(e)).await;
+                        return;
+                    }
+                    Err(_) => attempt += 1,
+                }
+            };
+
+            if tx.send(Ok(reconnected())).await.is_err() {
+                let _ = ws.close(None).await;
+                return;
+            }
+        }
+    });
+}
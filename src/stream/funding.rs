@@ -0,0 +1,64 @@
+//! Funding rate stream.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::Stream;
+use tokio::sync::mpsc;
+
+use super::connection::{self, CHANNEL_CAPACITY};
+use super::public::parse_envelope;
+use super::StreamHealth;
+use crate::config::EndpointConfig;
+use crate::error::Result;
+use crate::models::FundingRate;
+
+fn decode_funding(text: &str) -> Result<Vec<FundingRate>> {
+    Ok(vec![parse_envelope::<FundingRate>(text)?.data])
+}
+
+/// Stream of funding rate updates for a market.
+///
+/// Yields a [`FundingRate`] each time the exchange publishes a predicted or realized
+/// rate, so strategies can react to funding flips without polling `get_funding_rates`.
+pub struct FundingStream {
+    rx: mpsc::Receiver<Result<FundingRate>>,
+    health: StreamHealth,
+}
+
+impl FundingStream {
+    /// Connect to the funding channel of a market.
+    ///
+    /// # Arguments
+    /// * `config` - Endpoint configuration (uses `stream_base_url`)
+    /// * `market` - Market name (e.g., "BTC-USD")
+    pub async fn connect(config: &EndpointConfig, market: impl AsRef<str>) -> Result<Self> {
+        let market = market.as_ref();
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        let health = StreamHealth::default();
+
+        let ws = connection::connect(&config.stream_url(&format!("funding/{}", market))).await?;
+        connection::spawn_reader(ws, health.clone(), tx, decode_funding);
+
+        Ok(Self { rx, health })
+    }
+
+    /// Get the time elapsed since the last message.
+    pub fn last_message_age(&self) -> Duration {
+        self.health.last_message_age()
+    }
+
+    /// Check if a message was received within the keepalive window.
+    pub fn is_healthy(&self) -> bool {
+        self.health.is_healthy()
+    }
+}
+
+impl Stream for FundingStream {
+    type Item = Result<FundingRate>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
@@ -0,0 +1,78 @@
+//! Liveness tracking for streaming connections.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Default window within which a message must arrive for a stream to be healthy.
+pub const DEFAULT_KEEPALIVE_WINDOW: Duration = Duration::from_secs(30);
+
+/// Liveness tracker for a streaming connection.
+///
+/// The reader side calls `record_message()` for every frame received (including pings);
+/// a supervising task can then check `last_message_age()` / `is_healthy()` and decide
+/// to recreate the stream. Clones share the same underlying state.
+#[derive(Debug, Clone)]
+pub struct StreamHealth {
+    last_message: Arc<Mutex<Instant>>,
+    keepalive_window: Duration,
+}
+
+impl StreamHealth {
+    /// Create a tracker with the given keepalive window.
+    ///
+    /// The clock starts at creation, so a freshly connected stream is healthy
+    /// until the window elapses without a message.
+    pub fn new(keepalive_window: Duration) -> Self {
+        Self {
+            last_message: Arc::new(Mutex::new(Instant::now())),
+            keepalive_window,
+        }
+    }
+
+    /// Record that a message was just received.
+    pub fn record_message(&self) {
+        let mut last = self.last_message.lock().unwrap_or_else(|e| e.into_inner());
+        *last = Instant::now();
+    }
+
+    /// Get the time elapsed since the last message was received.
+    pub fn last_message_age(&self) -> Duration {
+        let last = self.last_message.lock().unwrap_or_else(|e| e.into_inner());
+        last.elapsed()
+    }
+
+    /// Check if a message was received within the keepalive window.
+    pub fn is_healthy(&self) -> bool {
+        self.last_message_age() <= self.keepalive_window
+    }
+
+    /// Get the keepalive window.
+    pub fn keepalive_window(&self) -> Duration {
+        self.keepalive_window
+    }
+}
+
+impl Default for StreamHealth {
+    fn default() -> Self {
+        Self::new(DEFAULT_KEEPALIVE_WINDOW)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_expires_and_recovers() {
+        let health = StreamHealth::new(Duration::from_millis(20));
+        assert!(health.is_healthy());
+
+        std::thread::sleep(Duration::from_millis(40));
+        assert!(!health.is_healthy());
+        assert!(health.last_message_age() >= Duration::from_millis(40));
+
+        // Clones share state with the original
+        health.clone().record_message();
+        assert!(health.is_healthy());
+    }
+}
@@ -0,0 +1,18 @@
+//! Streaming (WebSocket) support for Extended Exchange.
+
+mod candle;
+mod connection;
+mod funding;
+mod health;
+mod private;
+mod public;
+mod reconnecting;
+mod ticker;
+
+pub use candle::{CandleStream, CandleUpdate};
+pub use funding::FundingStream;
+pub use health::{StreamHealth, DEFAULT_KEEPALIVE_WINDOW};
+pub use private::{PrivateStream, PrivateStreamEvent};
+pub use public::{OrderBookUpdate, PublicStream, StreamChannel, StreamEvent, UpdateKind};
+pub use reconnecting::ReconnectingStream;
+pub use ticker::{Ticker, TickerStream};
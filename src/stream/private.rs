@@ -0,0 +1,207 @@
+//! Private account stream (orders, fills, positions and balance).
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::Stream;
+use serde::Deserialize;
+use tokio::sync::mpsc;
+
+use super::connection::{self, CHANNEL_CAPACITY};
+use super::StreamHealth;
+use crate::config::EndpointConfig;
+use crate::error::Result;
+use crate::models::{Balance, Order, Position, Trade};
+
+/// Event yielded by [`PrivateStream`].
+#[derive(Debug, Clone)]
+pub enum PrivateStreamEvent {
+    /// An order was created or changed status.
+    OrderUpdate(Order),
+    /// One of the account's orders was filled (fully or partially).
+    Fill(Trade),
+    /// A position was opened, changed or closed.
+    PositionUpdate(Position),
+    /// The account balance changed.
+    BalanceUpdate(Balance),
+}
+
+/// Account stream message: `{"type": "ORDER", "data": {"orders": [...]}, ...}`.
+#[derive(Debug, Deserialize)]
+struct AccountMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    data: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct Orders {
+    #[serde(default)]
+    orders: Vec<Order>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Trades {
+    #[serde(default)]
+    trades: Vec<Trade>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Positions {
+    #[serde(default)]
+    positions: Vec<Position>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BalanceData {
+    balance: Balance,
+}
+
+fn decode_account(text: &str) -> Result<Vec<PrivateStreamEvent>> {
+    let msg: AccountMessage = serde_json::from_str(text)?;
+    let events = match msg.kind.as_str() {
+        "ORDER" => {
+            let data: Orders = serde_json::from_value(msg.data)?;
+            data.orders.into_iter().map(PrivateStreamEvent::OrderUpdate).collect()
+        }
+        "TRADE" => {
+            let data: Trades = serde_json::from_value(msg.data)?;
+            data.trades.into_iter().map(PrivateStreamEvent::Fill).collect()
+        }
+        "POSITION" => {
+            let data: Positions = serde_json::from_value(msg.data)?;
+            data.positions.into_iter().map(PrivateStreamEvent::PositionUpdate).collect()
+        }
+        "BALANCE" => {
+            let data: BalanceData = serde_json::from_value(msg.data)?;
+            vec![PrivateStreamEvent::BalanceUpdate(data.balance)]
+        }
+        // Heartbeats and message types this SDK doesn't model yet
+        _ => Vec::new(),
+    };
+    Ok(events)
+}
+
+/// Private account stream.
+///
+/// Authenticates with the API key header and yields [`PrivateStreamEvent`]s for the
+/// account's order updates, fills, positions and balance. A rejected API key fails
+/// `connect` with `ExtendedError::Authentication`; if the server later drops the
+/// session (e.g. the key is revoked), an error is yielded before the stream ends
+/// rather than it ending silently. Dropping the stream closes the connection.
+///
+/// # Example
+/// ```no_run
+/// # async fn example() -> extended_rust_sdk::error::Result<()> {
+/// use extended_rust_sdk::config::mainnet_config;
+/// use extended_rust_sdk::stream::{PrivateStream, PrivateStreamEvent};
+/// use futures::StreamExt;
+///
+/// let mut stream = PrivateStream::connect(&mainnet_config(), "your-api-key").await?;
+/// while let Some(event) = stream.next().await {
+///     if let PrivateStreamEvent::Fill(fill) = event? {
+///         println!("filled {} @ {}", fill.quantity, fill.price);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct PrivateStream {
+    rx: mpsc::Receiver<Result<PrivateStreamEvent>>,
+    health: StreamHealth,
+}
+
+impl PrivateStream {
+    /// Connect to the account stream.
+    ///
+    /// # Arguments
+    /// * `config` - Endpoint configuration (uses `stream_base_url`)
+    /// * `api_key` - API key of the account
+    pub async fn connect(config: &EndpointConfig, api_key: &str) -> Result<Self> {
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        let health = StreamHealth::default();
+
+        let ws = connection::connect_with_api_key(&config.stream_url("account"), api_key).await?;
+        connection::spawn_reader(ws, health.clone(), tx, decode_account);
+
+        Ok(Self { rx, health })
+    }
+
+    /// Get the time elapsed since the last message.
+    pub fn last_message_age(&self) -> Duration {
+        self.health.last_message_age()
+    }
+
+    /// Check if a message was received within the keepalive window.
+    pub fn is_healthy(&self) -> bool {
+        self.health.is_healthy()
+    }
+}
+
+impl Stream for PrivateStream {
+    type Item = Result<PrivateStreamEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::testnet_config;
+    use crate::error::ExtendedError;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[test]
+    fn test_decode_account_messages() {
+        let orders = r#"{
+            "type": "ORDER",
+            "ts": 1700000000000,
+            "data": {"orders": [{
+                "id": 1001, "market": "BTC-USD", "side": "BUY", "type": "LIMIT",
+                "status": "FILLED", "price": "95000", "qty": "0.01"
+            }]}
+        }"#;
+        match decode_account(orders).unwrap().as_slice() {
+            [PrivateStreamEvent::OrderUpdate(order)] => assert_eq!(order.id, "1001"),
+            other => panic!("unexpected events: {:?}", other),
+        }
+
+        let balance = r#"{
+            "type": "BALANCE",
+            "data": {"balance": {"balance": "100", "equity": "105"}}
+        }"#;
+        match decode_account(balance).unwrap().as_slice() {
+            [PrivateStreamEvent::BalanceUpdate(b)] => assert_eq!(b.equity.to_string(), "105"),
+            other => panic!("unexpected events: {:?}", other),
+        }
+
+        assert!(decode_account(r#"{"type": "HEARTBEAT"}"#).unwrap().is_empty());
+        assert!(decode_account(r#"{"type": "TRADE", "data": {"trades": [{}]}}"#).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rejected_api_key_is_an_authentication_error() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 4096];
+            let _ = socket.read(&mut buf).await;
+            let _ = socket
+                .write_all(b"HTTP/1.1 401 Unauthorized\r\nContent-Length: 0\r\n\r\n")
+                .await;
+        });
+
+        let mut config = testnet_config();
+        config.stream_base_url = format!("ws://{}", addr);
+        match PrivateStream::connect(&config, "bad-key").await {
+            Err(ExtendedError::Authentication(msg)) => assert!(msg.contains("401")),
+            Err(other) => panic!("unexpected error: {:?}", other),
+            Ok(_) => panic!("connection should have been rejected"),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! Public market data stream (order book and trades).
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::Stream;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tokio::sync::mpsc;
+
+use super::connection::{self, CHANNEL_CAPACITY};
+use super::StreamHealth;
+use crate::config::EndpointConfig;
+use crate::error::Result;
+use crate::models::{OrderBook, OrderBookDelta, PublicTrade};
+
+/// Whether an order book message replaces the book or updates it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UpdateKind {
+    /// Full order book; replaces any local state.
+    Snapshot,
+    /// Changed levels only; a zero quantity removes the level.
+    Delta,
+}
+
+/// Order book message from the stream.
+#[derive(Debug, Clone)]
+pub struct OrderBookUpdate {
+    /// Snapshot or delta.
+    pub kind: UpdateKind,
+    /// Stream sequence number, used to detect gaps.
+    pub sequence: Option<i64>,
+    /// Book levels carried by the message.
+    pub book: OrderBook,
+}
+
+impl OrderBookUpdate {
+    /// Convert a delta message into an [`OrderBookDelta`] for `LocalOrderBook`.
+    ///
+    /// Returns `None` for snapshots and for messages without a sequence number.
+    pub fn into_delta(self) -> Option<OrderBookDelta> {
+        match (self.kind, self.sequence) {
+            (UpdateKind::Delta, Some(sequence)) => Some(OrderBookDelta {
+                sequence,
+                bids: self.book.bids,
+                asks: self.book.asks,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Event yielded by [`PublicStream`].
+#[derive(Debug, Clone)]
+pub enum StreamEvent {
+    /// Order book snapshot or delta.
+    OrderBookUpdate(OrderBookUpdate),
+    /// Public trade.
+    Trade(PublicTrade),
+    /// A dropped connection was re-established (only from [`super::ReconnectingStream`]).
+    ///
+    /// Messages on that channel may have been missed, so local state such as a
+    /// `LocalOrderBook` for the market should be rebuilt from the next snapshot.
+    Reconnected {
+        /// Market whose channel reconnected.
+        market: String,
+        /// Channel that reconnected.
+        channel: StreamChannel,
+    },
+}
+
+/// Per-market channel of [`PublicStream`] and [`super::ReconnectingStream`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamChannel {
+    /// Order book snapshots and deltas.
+    OrderBook,
+    /// Public trades.
+    Trades,
+}
+
+/// Message envelope used by the stream endpoints.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Envelope<T> {
+    #[serde(rename = "type", default)]
+    pub(crate) kind: Option<UpdateKind>,
+    pub(crate) data: T,
+    #[serde(default)]
+    pub(crate) seq: Option<i64>,
+}
+
+/// Parse a message envelope.
+pub(crate) fn parse_envelope<T: DeserializeOwned>(text: &str) -> Result<Envelope<T>> {
+    Ok(serde_json::from_str(text)?)
+}
+
+pub(crate) fn decode_orderbook(text: &str) -> Result<Vec<StreamEvent>> {
+    let env: Envelope<OrderBook> = parse_envelope(text)?;
+    let mut book = env.data;
+    let sequence = env.seq.or(book.sequence);
+    book.sequence = sequence;
+    Ok(vec![StreamEvent::OrderBookUpdate(OrderBookUpdate {
+        kind: env.kind.unwrap_or(UpdateKind::Snapshot),
+        sequence,
+        book,
+    })])
+}
+
+pub(crate) fn decode_trades(text: &str) -> Result<Vec<StreamEvent>> {
+    let env: Envelope<Vec<PublicTrade>> = parse_envelope(text)?;
+    Ok(env.data.into_iter().map(StreamEvent::Trade).collect())
+}
+
+/// Public market data stream.
+///
+/// Opens the order book and public trade channels for each requested market and merges
+/// them into a single `Stream` of [`StreamEvent`]s. Connection and protocol errors are
+/// yielded as `ExtendedError::WebSocket`; the stream ends once every connection has
+/// closed. Dropping the stream closes all connections.
+///
+/// # Example
+/// ```no_run
+/// # async fn example() -> extended_rust_sdk::error::Result<()> {
+/// use extended_rust_sdk::{config::mainnet_config, stream::{PublicStream, StreamEvent}};
+/// use futures::StreamExt;
+///
+/// let mut stream = PublicStream::connect(&mainnet_config(), &["BTC-USD"]).await?;
+/// while let Some(event) = stream.next().await {
+///     match event? {
+///         StreamEvent::OrderBookUpdate(update) => println!("book: {:?}", update.kind),
+///         StreamEvent::Trade(trade) => println!("trade: {} @ {}", trade.quantity, trade.price),
+///         StreamEvent::Reconnected { .. } => {}
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct PublicStream {
+    rx: mpsc::Receiver<Result<StreamEvent>>,
+    health: StreamHealth,
+}
+
+impl PublicStream {
+    /// Connect to the order book and trade channels of the given markets.
+    ///
+    /// # Arguments
+    /// * `config` - Endpoint configuration (uses `stream_base_url`)
+    /// * `markets` - Market names (e.g., `["BTC-USD"]`)
+    pub async fn connect(config: &EndpointConfig, markets: &[impl AsRef<str>]) -> Result<Self> {
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        let health = StreamHealth::default();
+
+        for market in markets {
+            let market = market.as_ref();
+            let ws = connection::connect(&config.stream_url(&format!("orderbooks/{}", market)))
+                .await?;
+            connection::spawn_reader(ws, health.clone(), tx.clone(), decode_orderbook);
+
+            let ws = connection::connect(&config.stream_url(&format!("publicTrades/{}", market)))
+                .await?;
+            connection::spawn_reader(ws, health.clone(), tx.clone(), decode_trades);
+        }
+
+        Ok(Self { rx, health })
+    }
+
+    /// Get the time elapsed since the last message on any channel.
+    pub fn last_message_age(&self) -> Duration {
+        self.health.last_message_age()
+    }
+
+    /// Check if a message was received within the keepalive window.
+    pub fn is_healthy(&self) -> bool {
+        self.health.is_healthy()
+    }
+}
+
+impl Stream for PublicStream {
+    type Item = Result<StreamEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_orderbook_and_trades() {
+        let book = r#"{
+            "type": "DELTA",
+            "seq": 42,
+            "data": {
+                "market": "BTC-USD",
+                "bids": [{"price": "50000", "quantity": "0.5"}],
+                "asks": [],
+                "timestamp": 1700000000000
+            }
+        }"#;
+        match decode_orderbook(book).unwrap().as_slice() {
+            [StreamEvent::OrderBookUpdate(update)] => {
+                assert_eq!(update.kind, UpdateKind::Delta);
+                assert_eq!(update.sequence, Some(42));
+                assert_eq!(update.book.bids.len(), 1);
+            }
+            other => panic!("unexpected events: {:?}", other),
+        }
+
+        let trades = r#"{
+            "data": [
+                {"id": "1", "market": "BTC-USD", "price": "50000", "quantity": "0.1",
+                 "side": "BUY", "timestamp": 1700000000000},
+                {"id": "2", "market": "BTC-USD", "price": "50001", "quantity": "0.2",
+                 "side": "SELL", "timestamp": 1700000000001}
+            ]
+        }"#;
+        assert_eq!(decode_trades(trades).unwrap().len(), 2);
+        assert!(decode_trades("not json").is_err());
+    }
+}
@@ -0,0 +1,203 @@
+//! Public market data stream that survives disconnects.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::Stream;
+use tokio::sync::mpsc;
+
+use super::connection::{self, CHANNEL_CAPACITY};
+use super::public::{decode_orderbook, decode_trades};
+use super::{StreamChannel, StreamEvent, StreamHealth};
+use crate::config::{EndpointConfig, RetryConfig};
+use crate::error::Result;
+
+type Decoder = fn(&str) -> Result<Vec<StreamEvent>>;
+
+/// Public market data stream that reconnects and resubscribes when a connection drops.
+///
+/// Subscribes to the same channels as [`super::PublicStream`]. When a channel's
+/// connection is closed or fails, it is reopened with exponential backoff following
+/// the given `RetryConfig` (where `max_attempts` is the number of consecutive failed
+/// reconnects tolerated), and [`StreamEvent::Reconnected`] naming the market and
+/// channel is yielded once it's back so that market's local order book can be
+/// resynced. Authentication rejections and running out of attempts are yielded as a
+/// final error for that channel.
+///
+/// # Example
+/// ```no_run
+/// # async fn example() -> extended_rust_sdk::error::Result<()> {
+/// use extended_rust_sdk::config::{mainnet_config, RetryConfig};
+/// use extended_rust_sdk::stream::{ReconnectingStream, StreamEvent};
+/// use futures::StreamExt;
+///
+/// let retry = RetryConfig::default().with_max_attempts(10);
+/// let mut stream = ReconnectingStream::connect(&mainnet_config(), &["BTC-USD"], retry).await?;
+/// while let Some(event) = stream.next().await {
+///     if let StreamEvent::Reconnected { market, channel } = event? {
+///         println!("{} {:?} reconnected, waiting for a fresh snapshot", market, channel);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct ReconnectingStream {
+    rx: mpsc::Receiver<Result<StreamEvent>>,
+    health: StreamHealth,
+}
+
+impl ReconnectingStream {
+    /// Connect to the order book and trade channels of the given markets.
+    ///
+    /// The initial connections are made before returning, so a bad URL or unreachable
+    /// server fails here rather than being retried.
+    ///
+    /// # Arguments
+    /// * `config` - Endpoint configuration (uses `stream_base_url`)
+    /// * `markets` - Market names (e.g., `["BTC-USD"]`)
+    /// * `retry` - Reconnection backoff and maximum consecutive attempts
+    pub async fn connect(
+        config: &EndpointConfig,
+        markets: &[impl AsRef<str>],
+        retry: RetryConfig,
+    ) -> Result<Self> {
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        let health = StreamHealth::default();
+
+        for market in markets {
+            let market = market.as_ref();
+            let channels: [(StreamChannel, String, Decoder); 2] = [
+                (StreamChannel::OrderBook, format!("orderbooks/{}", market), decode_orderbook),
+                (StreamChannel::Trades, format!("publicTrades/{}", market), decode_trades),
+            ];
+            for (channel, path, decode) in channels {
+                let url = config.stream_url(&path);
+                let ws = connection::connect(&url).await?;
+                let market = market.to_string();
+                connection::spawn_reconnecting_reader(
+                    ws,
+                    url,
+                    retry.clone(),
+                    health.clone(),
+                    tx.clone(),
+                    decode,
+                    move || StreamEvent::Reconnected {
+                        market: market.clone(),
+                        channel,
+                    },
+                );
+            }
+        }
+
+        Ok(Self { rx, health })
+    }
+
+    /// Get the time elapsed since the last message on any channel.
+    pub fn last_message_age(&self) -> Duration {
+        self.health.last_message_age()
+    }
+
+    /// Check if a message was received within the keepalive window.
+    pub fn is_healthy(&self) -> bool {
+        self.health.is_healthy()
+    }
+}
+
+impl Stream for ReconnectingStream {
+    type Item = Result<StreamEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::testnet_config;
+    use futures::{SinkExt, StreamExt};
+    use std::sync::{Arc, Mutex};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+    use tokio_tungstenite::tungstenite::Message;
+
+    const BOOK: &str = r#"{"type": "SNAPSHOT", "seq": 1, "data":
+        {"market": "BTC-USD", "bids": [], "asks": [], "timestamp": 1700000000000}}"#;
+
+    /// Mock server that drops the first order book connection after one message.
+    async fn mock_server(paths: Arc<Mutex<Vec<String>>>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                let paths = paths.clone();
+                tokio::spawn(async move {
+                    let mut path = String::new();
+                    // Handshake callback signature is fixed by tungstenite
+                    #[allow(clippy::result_large_err)]
+                    let record = |req: &Request, resp: Response| {
+                        path = req.uri().path().to_string();
+                        Ok(resp)
+                    };
+                    let mut ws = tokio_tungstenite::accept_hdr_async(socket, record)
+                        .await
+                        .unwrap();
+                    let first = {
+                        let mut paths = paths.lock().unwrap();
+                        paths.push(path.clone());
+                        paths.iter().filter(|p| **p == path).count() == 1
+                    };
+                    if path.starts_with("/orderbooks/") {
+                        ws.send(Message::text(BOOK)).await.unwrap();
+                        if first {
+                            return; // drop the socket without a close frame
+                        }
+                    }
+                    // Keep the connection open until the client goes away
+                    while let Some(Ok(_)) = ws.next().await {}
+                });
+            }
+        });
+        format!("ws://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_and_resubscribes_after_drop() {
+        let paths = Arc::new(Mutex::new(Vec::new()));
+        let mut config = testnet_config();
+        config.stream_base_url = mock_server(paths.clone()).await;
+        let retry = RetryConfig::default()
+            .with_base_delay(Duration::from_millis(5))
+            .with_max_attempts(3);
+
+        let mut stream = ReconnectingStream::connect(&config, &["BTC-USD"], retry)
+            .await
+            .unwrap();
+
+        let mut events = Vec::new();
+        while events.len() < 3 {
+            let event = tokio::time::timeout(Duration::from_secs(5), stream.next())
+                .await
+                .expect("stream stalled")
+                .expect("stream ended")
+                .unwrap();
+            events.push(event);
+        }
+
+        // Snapshot, reconnect marker, then a fresh snapshot from the new connection
+        assert!(matches!(events[0], StreamEvent::OrderBookUpdate(_)));
+        assert!(matches!(
+            events[1],
+            StreamEvent::Reconnected { ref market, channel: StreamChannel::OrderBook }
+                if market == "BTC-USD"
+        ));
+        assert!(matches!(events[2], StreamEvent::OrderBookUpdate(_)));
+
+        let paths = paths.lock().unwrap();
+        let book_subscriptions = paths.iter().filter(|p| *p == "/orderbooks/BTC-USD").count();
+        assert_eq!(book_subscriptions, 2);
+        assert_eq!(paths.iter().filter(|p| *p == "/publicTrades/BTC-USD").count(), 1);
+    }
+}
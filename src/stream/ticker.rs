@@ -0,0 +1,413 @@
+//! Best bid/ask stream derived from the order book channel.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::{Stream, StreamExt};
+use rust_decimal::Decimal;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+use super::connection::{self, CHANNEL_CAPACITY};
+use super::public::decode_orderbook;
+use super::{OrderBookUpdate, StreamChannel, StreamEvent, StreamHealth, UpdateKind};
+use crate::config::EndpointConfig;
+use crate::error::Result;
+use crate::models::LocalOrderBook;
+
+/// Top of the book for a market.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ticker {
+    /// Market name.
+    pub market: String,
+    /// Best bid price, `None` while the bid side is empty.
+    pub best_bid: Option<Decimal>,
+    /// Best ask price, `None` while the ask side is empty.
+    pub best_ask: Option<Decimal>,
+    /// Mid price, `None` unless both sides have levels.
+    pub mid: Option<Decimal>,
+    /// Timestamp of the order book message that produced this ticker (Unix ms).
+    pub timestamp: i64,
+}
+
+impl Ticker {
+    fn same_top(&self, other: &Ticker) -> bool {
+        self.best_bid == other.best_bid && self.best_ask == other.best_ask
+    }
+}
+
+/// Keeps a `LocalOrderBook` for one market from order book messages.
+#[derive(Debug)]
+struct TopOfBook {
+    market: String,
+    book: Option<LocalOrderBook>,
+}
+
+impl TopOfBook {
+    /// Apply a message, returning the resulting ticker once a snapshot has been seen.
+    ///
+    /// On a sequence gap the book is dropped until the next snapshot and the error is
+    /// returned.
+    fn apply(&mut self, update: OrderBookUpdate) -> Result<Option<Ticker>> {
+        let timestamp = update.book.timestamp;
+        match update.kind {
+            UpdateKind::Snapshot => self.book = Some(LocalOrderBook::new(update.book)),
+            UpdateKind::Delta => {
+                let (Some(book), Some(delta)) = (self.book.as_mut(), update.into_delta()) else {
+                    return Ok(None);
+                };
+                if let Err(e) = book.apply_update(delta) {
+                    self.book = None;
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(self.book.as_ref().map(|book| Ticker {
+            market: self.market.clone(),
+            best_bid: book.best_bid().map(|level| level.price),
+            best_ask: book.best_ask().map(|level| level.price),
+            mid: book.mid_price(),
+            timestamp,
+        }))
+    }
+}
+
+/// Stream of best bid/ask changes for a market.
+///
+/// Maintains a `LocalOrderBook` from the order book channel and yields a [`Ticker`]
+/// only when the best bid or ask price changes, so updates deeper in the book or to the
+/// quantity at the top are skipped. With a `min_interval`, at most one ticker is yielded
+/// per interval; changes in between are coalesced into the latest one, which is yielded
+/// once the interval has passed.
+///
+/// A sequence gap is yielded as `ExtendedError::SequenceGap`, after which tickers resume
+/// from the next snapshot. Other errors are yielded as they come from the underlying
+/// events.
+///
+/// # Example
+/// ```no_run
+/// # async fn example() -> extended_rust_sdk::error::Result<()> {
+/// use std::time::Duration;
+///
+/// use extended_rust_sdk::{config::mainnet_config, stream::TickerStream};
+/// use futures::StreamExt;
+///
+/// let interval = Some(Duration::from_millis(100));
+/// let mut tickers = TickerStream::connect(&mainnet_config(), "BTC-USD", interval).await?;
+/// while let Some(ticker) = tickers.next().await {
+///     let ticker = ticker?;
+///     println!("{:?} / {:?}", ticker.best_bid, ticker.best_ask);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct TickerStream {
+    rx: mpsc::Receiver<Result<Ticker>>,
+    health: Option<StreamHealth>,
+}
+
+impl TickerStream {
+    /// Connect to the order book channel of a market.
+    ///
+    /// # Arguments
+    /// * `config` - Endpoint configuration (uses `stream_base_url`)
+    /// * `market` - Market name (e.g., "BTC-USD")
+    /// * `min_interval` - Minimum time between tickers, or `None` to yield every change
+    pub async fn connect(
+        config: &EndpointConfig,
+        market: impl AsRef<str>,
+        min_interval: Option<Duration>,
+    ) -> Result<Self> {
+        let market = market.as_ref();
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        let health = StreamHealth::default();
+
+        let ws = connection::connect(&config.stream_url(&format!("orderbooks/{}", market)))
+            .await?;
+        connection::spawn_reader(ws, health.clone(), tx, decode_orderbook);
+
+        let mut stream = Self::from_events(rx_stream(rx), market, min_interval);
+        stream.health = Some(health);
+        Ok(stream)
+    }
+
+    /// Derive tickers from existing stream events, e.g. a [`super::ReconnectingStream`].
+    ///
+    /// Only order book messages for `market` are used. [`StreamEvent::Reconnected`] for
+    /// the order book channel of `market` drops the book until the next snapshot.
+    pub fn from_events<S>(
+        events: S,
+        market: impl Into<String>,
+        min_interval: Option<Duration>,
+    ) -> Self
+    where
+        S: Stream<Item = Result<StreamEvent>> + Send + Unpin + 'static,
+    {
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        let top = TopOfBook {
+            market: market.into(),
+            book: None,
+        };
+        tokio::spawn(run(events, top, min_interval, tx));
+        Self { rx, health: None }
+    }
+
+    /// Get the time elapsed since the last message on the order book channel.
+    ///
+    /// Returns `None` for a stream created with `from_events`.
+    pub fn last_message_age(&self) -> Option<Duration> {
+        self.health.as_ref().map(StreamHealth::last_message_age)
+    }
+
+    /// Check if a message was received within the keepalive window.
+    ///
+    /// Always true for a stream created with `from_events`.
+    pub fn is_healthy(&self) -> bool {
+        self.health.as_ref().is_none_or(StreamHealth::is_healthy)
+    }
+}
+
+impl Stream for TickerStream {
+    type Item = Result<Ticker>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+fn rx_stream<T: Send + 'static>(
+    mut rx: mpsc::Receiver<T>,
+) -> impl Stream<Item = T> + Send + Unpin + 'static {
+    Box::pin(futures::stream::poll_fn(move |cx| rx.poll_recv(cx)))
+}
+
+/// Turn events into tickers until either side goes away.
+async fn run<S>(
+    mut events: S,
+    mut top: TopOfBook,
+    min_interval: Option<Duration>,
+    tx: mpsc::Sender<Result<Ticker>>,
+) where
+    S: Stream<Item = Result<StreamEvent>> + Unpin,
+{
+    let mut last_sent: Option<Ticker> = None;
+    let mut pending: Option<Ticker> = None;
+    let mut next_send = Instant::now();
+
+    loop {
+        let event = tokio::select! {
+            _ = tx.closed() => return,
+            _ = tokio::time::sleep_until(next_send), if pending.is_some() => None,
+            event = events.next() => Some(event),
+        };
+
+        let ticker = match event {
+            // The throttle interval passed with a ticker waiting
+            None => pending.take(),
+            Some(None) => {
+                if let Some(ticker) = pending.take() {
+                    let _ = tx.send(Ok(ticker)).await;
+                }
+                return;
+            }
+            Some(Some(Ok(StreamEvent::OrderBookUpdate(update)))) => {
+                if update.book.market != top.market {
+                    continue;
+                }
+                match top.apply(update) {
+                    Ok(ticker) => ticker,
+                    Err(e) => {
+                        if tx.send(Err(e)).await.is_err() {
+                            return;
+                        }
+                        continue;
+                    }
+                }
+            }
+            Some(Some(Ok(StreamEvent::Reconnected { market, channel }))) => {
+                if market == top.market && channel == StreamChannel::OrderBook {
+                    top.book = None;
+                }
+                continue;
+            }
+            Some(Some(Ok(_))) => continue,
+            Some(Some(Err(e))) => {
+                if tx.send(Err(e)).await.is_err() {
+                    return;
+                }
+                continue;
+            }
+        };
+
+        let Some(ticker) = ticker else { continue };
+        if last_sent.as_ref().is_some_and(|last| last.same_top(&ticker)) {
+            // Back where it was before the throttled changes: nothing to report
+            pending = None;
+            continue;
+        }
+        let now = Instant::now();
+        if now < next_send {
+            pending = Some(ticker);
+            continue;
+        }
+        if tx.send(Ok(ticker.clone())).await.is_err() {
+            return;
+        }
+        pending = None;
+        last_sent = Some(ticker);
+        if let Some(interval) = min_interval {
+            next_send = now + interval;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{OrderBook, PriceQuantity};
+    use rust_decimal_macros::dec;
+
+    fn update(kind: UpdateKind, sequence: i64, bids: &[(Decimal, Decimal)]) -> StreamEvent {
+        update_with_asks(kind, sequence, bids, &[(dec!(101), dec!(1))])
+    }
+
+    fn update_with_asks(
+        kind: UpdateKind,
+        sequence: i64,
+        bids: &[(Decimal, Decimal)],
+        asks: &[(Decimal, Decimal)],
+    ) -> StreamEvent {
+        let levels = |levels: &[(Decimal, Decimal)]| {
+            levels
+                .iter()
+                .map(|&(price, quantity)| PriceQuantity { price, quantity })
+                .collect()
+        };
+        StreamEvent::OrderBookUpdate(OrderBookUpdate {
+            kind,
+            sequence: Some(sequence),
+            book: OrderBook {
+                market: "BTC-USD".to_string(),
+                bids: levels(bids),
+                asks: levels(asks),
+                timestamp: 1_700_000_000_000 + sequence,
+                sequence: Some(sequence),
+            },
+        })
+    }
+
+    fn reconnected(market: &str, channel: StreamChannel) -> StreamEvent {
+        StreamEvent::Reconnected {
+            market: market.to_string(),
+            channel,
+        }
+    }
+
+    async fn tickers(events: Vec<StreamEvent>, min_interval: Option<Duration>) -> Vec<Ticker> {
+        let events = futures::stream::iter(events.into_iter().map(Ok));
+        TickerStream::from_events(events, "BTC-USD", min_interval)
+            .map(|ticker| ticker.unwrap())
+            .collect()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_tickers_only_on_top_of_book_changes() {
+        use UpdateKind::{Delta, Snapshot};
+
+        let events = vec![
+            // Deltas before the first snapshot are ignored
+            update(Delta, 1, &[(dec!(100), dec!(1))]),
+            update(Snapshot, 10, &[(dec!(100), dec!(1)), (dec!(99), dec!(2))]),
+            // Quantity at the top and a deeper level: no change
+            update_with_asks(Delta, 11, &[(dec!(100), dec!(5)), (dec!(98), dec!(1))], &[]),
+            // New best bid
+            update_with_asks(Delta, 12, &[(dec!(100.5), dec!(1))], &[]),
+            // Best bid removed, back to 100
+            update_with_asks(Delta, 13, &[(dec!(100.5), dec!(0))], &[]),
+            // Ask side emptied
+            update_with_asks(Delta, 14, &[], &[(dec!(101), dec!(0))]),
+            // Other markets and channels reconnecting don't affect the book
+            reconnected("ETH-USD", StreamChannel::OrderBook),
+            reconnected("BTC-USD", StreamChannel::Trades),
+            update_with_asks(Delta, 15, &[], &[(dec!(101), dec!(1))]),
+            reconnected("BTC-USD", StreamChannel::OrderBook),
+            update(Delta, 16, &[(dec!(200), dec!(1))]),
+            update(Snapshot, 20, &[(dec!(99.5), dec!(1))]),
+        ];
+
+        let tickers = tickers(events, None).await;
+        let tops: Vec<_> = tickers.iter().map(|t| (t.best_bid, t.best_ask, t.mid)).collect();
+        assert_eq!(
+            tops,
+            [
+                (Some(dec!(100)), Some(dec!(101)), Some(dec!(100.5))),
+                (Some(dec!(100.5)), Some(dec!(101)), Some(dec!(100.75))),
+                (Some(dec!(100)), Some(dec!(101)), Some(dec!(100.5))),
+                (Some(dec!(100)), None, None),
+                (Some(dec!(100)), Some(dec!(101)), Some(dec!(100.5))),
+                (Some(dec!(99.5)), Some(dec!(101)), Some(dec!(100.25))),
+            ]
+        );
+        assert_eq!(tickers[1].timestamp, 1_700_000_000_012);
+        assert!(tickers.iter().all(|t| t.market == "BTC-USD"));
+    }
+
+    #[tokio::test]
+    async fn test_sequence_gap_waits_for_snapshot() {
+        use UpdateKind::{Delta, Snapshot};
+
+        let events = futures::stream::iter(
+            [
+                update(Snapshot, 10, &[(dec!(100), dec!(1))]),
+                update(Delta, 12, &[(dec!(150), dec!(1))]),
+                update(Delta, 13, &[(dec!(160), dec!(1))]),
+                update(Snapshot, 20, &[(dec!(99), dec!(1))]),
+            ]
+            .map(Ok),
+        );
+        let results: Vec<_> = TickerStream::from_events(events, "BTC-USD", None).collect().await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().best_bid, Some(dec!(100)));
+        assert!(matches!(
+            results[1],
+            Err(crate::error::ExtendedError::SequenceGap { expected: 11, received: 12 })
+        ));
+        assert_eq!(results[2].as_ref().unwrap().best_bid, Some(dec!(99)));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_throttled_tickers_coalesce() {
+        let (tx, rx) = mpsc::channel(16);
+        let interval = Some(Duration::from_secs(1));
+        let mut stream = TickerStream::from_events(rx_stream(rx), "BTC-USD", interval);
+        let start = Instant::now();
+        let send = |event| {
+            let tx = tx.clone();
+            async move { tx.send(Ok(event)).await.unwrap() }
+        };
+
+        send(update(UpdateKind::Snapshot, 1, &[(dec!(100), dec!(1))])).await;
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!((first.best_bid, start.elapsed()), (Some(dec!(100)), Duration::ZERO));
+
+        // Two changes within the interval: only the latest is yielded, once it has passed
+        send(update(UpdateKind::Delta, 2, &[(dec!(100.5), dec!(1))])).await;
+        send(update(UpdateKind::Delta, 3, &[(dec!(100.7), dec!(1))])).await;
+        let second = stream.next().await.unwrap().unwrap();
+        assert_eq!(second.best_bid, Some(dec!(100.7)));
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+
+        // A change that's undone within the interval yields nothing
+        send(update(UpdateKind::Delta, 4, &[(dec!(100.8), dec!(1))])).await;
+        send(update(UpdateKind::Delta, 5, &[(dec!(100.8), dec!(0))])).await;
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        send(update(UpdateKind::Delta, 6, &[(dec!(100.9), dec!(1))])).await;
+        let third = stream.next().await.unwrap().unwrap();
+        assert_eq!(third.best_bid, Some(dec!(100.9)));
+        assert_eq!(third.timestamp, 1_700_000_000_006);
+    }
+}
@@ -0,0 +1,105 @@
+//! Tests for placing several orders in one call.
+
+mod common;
+
+use extended_rust_sdk::error::ExtendedError;
+use extended_rust_sdk::models::{CreateOrderRequest, OrderBuilder, OrderSide};
+use extended_rust_sdk::TradingClient;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use common::{mock_config, ok, test_account};
+
+fn order(price: Decimal, nonce: u64) -> CreateOrderRequest {
+    OrderBuilder::limit("BTC-USD", OrderSide::Buy, price, dec!(0.01), false, false)
+        .nonce(nonce)
+        .build()
+}
+
+async fn mount_order_endpoint(server: &MockServer) {
+    // Orders priced at 1 are rejected; mounted first so it takes priority
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order"))
+        .and(body_partial_json(json!({ "price": "1" })))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "status": "ERROR",
+            "error": { "code": 1121, "message": "Invalid price" }
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(ok(json!({ "id": 1, "externalId": "ext" }))),
+        )
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_create_orders_all_succeed() {
+    let server = MockServer::start().await;
+    mount_order_endpoint(&server).await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let orders = (0..8).map(|n| order(dec!(95000) + Decimal::from(n), n)).collect();
+    let results = client.private().create_orders(orders).await;
+
+    assert_eq!(results.len(), 8);
+    assert!(results.iter().all(|r| r.is_ok()));
+    assert_eq!(server.received_requests().await.unwrap().len(), 8);
+}
+
+#[tokio::test]
+async fn test_create_orders_reports_each_failure() {
+    let server = MockServer::start().await;
+    mount_order_endpoint(&server).await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let orders = vec![order(dec!(95000), 1), order(dec!(1), 2), order(dec!(95001), 3)];
+    let results = client.private().create_orders(orders).await;
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(matches!(
+        results[1],
+        Err(ExtendedError::OrderValidation(ref m)) if m == "Invalid price"
+    ));
+    assert!(results[2].is_ok());
+}
+
+#[tokio::test]
+async fn test_external_id_tracking_covers_every_submission_path() {
+    let server = MockServer::start().await;
+    mount_order_endpoint(&server).await;
+
+    let client = TradingClient::new(mock_config(&server), test_account())
+        .unwrap()
+        .with_external_id_tracking();
+    let with_id = |id: &str, nonce| {
+        let mut order = order(dec!(95000), nonce);
+        order.id = id.to_string();
+        order
+    };
+    let is_duplicate = |result: &Result<_, ExtendedError>| {
+        matches!(result, Err(ExtendedError::OrderValidation(m)) if m.contains("Duplicate"))
+    };
+
+    assert!(client.submit_order(with_id("grid-1", 1)).await.is_ok());
+    assert!(is_duplicate(&client.private().create_order(with_id("grid-1", 2)).await));
+    assert!(is_duplicate(&client.private().replace_order("grid-0", with_id("grid-1", 3)).await));
+
+    let results = client
+        .private()
+        .create_orders(vec![with_id("grid-2", 4), with_id("grid-2", 5), with_id("grid-1", 6)])
+        .await;
+    assert!(results[0].is_ok());
+    assert!(is_duplicate(&results[1]));
+    assert!(is_duplicate(&results[2]));
+
+    // Only the first submission of each ID reached the exchange
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
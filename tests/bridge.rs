@@ -0,0 +1,92 @@
+//! Bridge endpoints against a mock Extended server.
+
+mod common;
+
+use extended_rust_sdk::error::ExtendedError;
+use extended_rust_sdk::models::BridgeQuoteRequest;
+use extended_rust_sdk::TradingClient;
+use rust_decimal_macros::dec;
+use serde_json::json;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use common::{mock_config, ok, test_account, API_KEY};
+
+async fn mount_config(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/bridge/config"))
+        .and(header("X-Api-Key", API_KEY))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!({
+            "chains": [
+                {
+                    "chainId": 42161, "name": "Arbitrum", "minDeposit": "10",
+                    "depositFee": "0.5", "depositsEnabled": true
+                },
+                {
+                    "chainId": 8453, "name": "Base", "minDeposit": "10",
+                    "depositFee": "0.5", "depositsEnabled": false
+                }
+            ]
+        }))))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_get_bridge_config_and_quote() {
+    let server = MockServer::start().await;
+    mount_config(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/bridge/quote"))
+        .and(query_param("chainId", "42161"))
+        .and(query_param("amount", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!({
+            "quoteId": "q-1",
+            "chainId": 42161,
+            "inputAmount": "100",
+            "outputAmount": "99.5",
+            "fee": "0.5",
+            "expiresAt": 1700000060000i64
+        }))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+
+    let config = client.private().get_bridge_config().await.unwrap();
+    assert_eq!(config.chains.len(), 2);
+    assert_eq!(config.chain(42161).unwrap().name, "Arbitrum");
+
+    let request = BridgeQuoteRequest { chain_id: 42161, amount: dec!(100) };
+    let quote = client.private().get_bridge_quote(request).await.unwrap();
+    assert_eq!(quote.quote_id, "q-1");
+    assert_eq!(quote.output_amount, dec!(99.5));
+}
+
+#[tokio::test]
+async fn test_bridge_quote_rejects_unsupported_chains() {
+    let server = MockServer::start().await;
+    mount_config(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/bridge/quote"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+
+    let request = BridgeQuoteRequest { chain_id: 1, amount: dec!(100) };
+    match client.private().get_bridge_quote(request).await {
+        Err(ExtendedError::InvalidParameter(msg)) => {
+            assert!(msg.contains("Chain 1 is not supported"), "unexpected message: {msg}");
+            assert!(msg.contains("Arbitrum (42161)"), "unexpected message: {msg}");
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let request = BridgeQuoteRequest { chain_id: 8453, amount: dec!(100) };
+    let err = client.private().get_bridge_quote(request).await.unwrap_err();
+    assert!(err.to_string().contains("disabled"), "unexpected error: {err}");
+}
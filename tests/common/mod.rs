@@ -0,0 +1,95 @@
+//! Shared fixtures for integration tests against a mock Extended server.
+
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use extended_rust_sdk::config::{testnet_config, EndpointConfig, RetryConfig};
+use extended_rust_sdk::models::StarkAccount;
+use extended_rust_sdk::signing::StarkSigner;
+use serde_json::{json, Value};
+use wiremock::MockServer;
+
+/// Test API key sent by clients built with `test_account()`.
+pub const API_KEY: &str = "test-api-key";
+
+/// Test Stark private key (not a real account).
+pub const PRIVATE_KEY: &str = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+/// Test vault ID.
+pub const VAULT_ID: &str = "10001";
+
+/// Testnet configuration pointed at the mock server, with near-instant retries.
+pub fn mock_config(server: &MockServer) -> EndpointConfig {
+    let mut config = testnet_config();
+    config.api_base_url = server.uri();
+    config.with_retry(RetryConfig::default().with_base_delay(Duration::from_millis(1)))
+}
+
+/// Test account whose public key matches `PRIVATE_KEY`.
+pub fn test_account() -> StarkAccount {
+    let signer = StarkSigner::from_hex(PRIVATE_KEY).unwrap();
+    StarkAccount::new(API_KEY, signer.public_key_hex(), PRIVATE_KEY, VAULT_ID)
+}
+
+/// Canned market payload in the shape returned by `info/markets`.
+pub fn market_json(name: &str) -> Value {
+    let asset = name.split('-').next().unwrap_or(name);
+    json!({
+        "name": name,
+        "uiName": name,
+        "category": "L1",
+        "assetName": asset,
+        "assetPrecision": 5,
+        "collateralAssetName": "USD",
+        "collateralAssetPrecision": 6,
+        "active": true,
+        "status": "ACTIVE",
+        "tradingConfig": {
+            "minOrderSize": "0.0001",
+            "minOrderSizeChange": "0.00001",
+            "minPriceChange": "1",
+            "maxMarketOrderValue": "1000000",
+            "maxLimitOrderValue": "5000000",
+            "maxPositionValue": "10000000",
+            "maxLeverage": "50.00",
+            "maxNumOrders": "200",
+            "limitPriceCap": "0.05",
+            "limitPriceFloor": "0.05",
+            "riskFactorConfig": [
+                { "upperBound": "400000", "riskFactor": "0.02" },
+                { "upperBound": "800000", "riskFactor": "0.04" },
+                { "upperBound": "1000000000", "riskFactor": "0.1" }
+            ]
+        },
+        "marketStats": {
+            "dailyVolume": "1000000",
+            "dailyVolumeBase": "10",
+            "dailyPriceChange": "100",
+            "dailyPriceChangePercentage": "0.001",
+            "dailyLow": "99000",
+            "dailyHigh": "101000",
+            "lastPrice": "100000",
+            "askPrice": "100001",
+            "bidPrice": "99999",
+            "markPrice": "100000",
+            "indexPrice": "100000",
+            "fundingRate": "0.0001",
+            "nextFundingRate": 1700000000000i64,
+            "openInterest": "5000000",
+            "openInterestBase": "50"
+        },
+        "l2Config": {
+            "type": "STARKNET",
+            "collateralId": "0x1",
+            "collateralResolution": 1000000,
+            "syntheticId": "0x4254432d3600000000000000000000",
+            "syntheticResolution": 1000000
+        }
+    })
+}
+
+/// Wrap data in the standard `{"status": "OK", "data": ...}` envelope.
+pub fn ok(data: Value) -> Value {
+    json!({ "status": "OK", "data": data })
+}
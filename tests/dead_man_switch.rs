@@ -0,0 +1,69 @@
+//! Tests of the dead man's switch keepalive task against a mock transport.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use extended_rust_sdk::client::MockTransport;
+use extended_rust_sdk::config::testnet_config;
+use extended_rust_sdk::error::ExtendedError;
+use extended_rust_sdk::TradingClient;
+use serde_json::json;
+
+use common::{ok, test_account};
+
+fn client(transport: &Arc<MockTransport>) -> TradingClient {
+    let config = testnet_config().with_transport(transport.clone());
+    TradingClient::new(config, test_account()).unwrap()
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_guard_refreshes_until_dropped() {
+    let transport = Arc::new(MockTransport::new().with_json("user/deadmanswitch", ok(json!({}))));
+    let client = client(&transport);
+
+    let guard = client.private().spawn_dead_man_switch(30, Duration::from_secs(10)).unwrap();
+    assert!(guard.is_active());
+
+    // One refresh straight away, then one per interval
+    tokio::time::sleep(Duration::from_secs(35)).await;
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 4);
+    assert!(requests.iter().all(|r| r.url.query() == Some("countdownTime=30")));
+    assert_eq!(guard.consecutive_failures(), 0);
+    assert_eq!(guard.last_error(), None);
+
+    drop(guard);
+    tokio::time::sleep(Duration::from_secs(60)).await;
+    assert_eq!(transport.requests().len(), 4);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_failed_refreshes_are_reported() {
+    let transport = Arc::new(MockTransport::new().with_response(
+        "user/deadmanswitch",
+        500,
+        json!({ "status": "ERROR", "error": { "code": 500, "message": "Internal error" } }),
+    ));
+    let client = client(&transport);
+
+    let guard = client.private().spawn_dead_man_switch(30, Duration::from_secs(10)).unwrap();
+    tokio::time::sleep(Duration::from_secs(15)).await;
+
+    assert_eq!(guard.consecutive_failures(), 2);
+    assert!(guard.last_error().unwrap().contains("Internal error"));
+    assert!(guard.is_active());
+}
+
+#[tokio::test]
+async fn test_refresh_interval_must_be_shorter_than_countdown() {
+    let transport = Arc::new(MockTransport::new());
+    let client = client(&transport);
+
+    for interval in [Duration::from_secs(30), Duration::from_secs(45)] {
+        let result = client.private().spawn_dead_man_switch(30, interval);
+        assert!(matches!(result, Err(ExtendedError::InvalidParameter(_))), "{:?}", result);
+    }
+    assert!(transport.requests().is_empty());
+}
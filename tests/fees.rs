@@ -0,0 +1,69 @@
+//! Tests of fee tier lookup and fee estimation against a mock Extended server.
+
+mod common;
+
+use extended_rust_sdk::error::ExtendedError;
+use extended_rust_sdk::TradingClient;
+use rust_decimal_macros::dec;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use common::{mock_config, ok, test_account};
+
+async fn mount_fees(server: &MockServer, expected_calls: u64) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/fees"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([
+            { "market": "BTC-USD", "makerFeeRate": "0.0001", "takerFeeRate": "0.0003" },
+            { "market": "ETH-USD", "makerFeeRate": "0", "takerFeeRate": "0.00025" }
+        ]))))
+        .expect(expected_calls)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_estimate_order_fee_uses_cached_tier() {
+    let server = MockServer::start().await;
+    mount_fees(&server, 1).await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let api = client.private();
+
+    // 0.2 BTC at 50,000 = 10,000 notional
+    let taker = api
+        .estimate_order_fee("BTC-USD", dec!(50000), dec!(0.2), false)
+        .await
+        .unwrap();
+    assert_eq!(taker, dec!(3));
+    let maker = api
+        .estimate_order_fee("BTC-USD", dec!(50000), dec!(0.2), true)
+        .await
+        .unwrap();
+    assert_eq!(maker, dec!(1));
+
+    let eth_maker = api
+        .estimate_order_fee("ETH-USD", dec!(3000), dec!(2), true)
+        .await
+        .unwrap();
+    assert_eq!(eth_maker, dec!(0));
+
+    // Only the first lookup hit the API; the mock's expectation is checked on drop
+}
+
+#[tokio::test]
+async fn test_unknown_market_and_refresh() {
+    let server = MockServer::start().await;
+    mount_fees(&server, 2).await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let api = client.private();
+
+    let result = api.market_fee("DOGE-USD").await;
+    assert!(matches!(result, Err(ExtendedError::InvalidParameter(_))));
+
+    api.refresh_fees().await.unwrap();
+    let fee = api.market_fee("ETH-USD").await.unwrap();
+    assert_eq!(fee.get_taker_fee_rate(), dec!(0.00025));
+}
@@ -0,0 +1,252 @@
+//! Tests of public market data helpers against a mock Extended server.
+
+mod common;
+
+use extended_rust_sdk::error::ExtendedError;
+use extended_rust_sdk::models::{CandleType, MarketName, OrderSide, TimeInterval};
+use extended_rust_sdk::TradingClient;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use common::{market_json, mock_config, ok, test_account};
+
+#[tokio::test]
+async fn test_asset_prices_come_from_usd_markets() {
+    let server = MockServer::start().await;
+
+    let mut eth = market_json("ETH-USD");
+    eth["marketStats"]["indexPrice"] = json!("3500.5");
+    // Not USD-quoted, so it must not provide a price for BTC
+    let mut btc_eth = market_json("BTC-ETH");
+    btc_eth["collateralAssetName"] = json!("ETH");
+    btc_eth["marketStats"]["indexPrice"] = json!("28");
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(ok(json!([btc_eth, market_json("BTC-USD"), eth]))),
+        )
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let public = client.public();
+
+    let prices = public.get_asset_prices().await.unwrap();
+    assert_eq!(prices.len(), 3);
+    assert_eq!(prices["BTC"], dec!(100000));
+    assert_eq!(prices["ETH"], dec!(3500.5));
+    assert_eq!(prices["USD"], Decimal::ONE);
+
+    assert_eq!(public.get_asset_price("ETH").await.unwrap(), dec!(3500.5));
+    assert_eq!(public.get_asset_price("USD").await.unwrap(), Decimal::ONE);
+    assert!(matches!(
+        public.get_asset_price("DOGE").await,
+        Err(ExtendedError::InvalidParameter(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_get_market_filters_by_name() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .and(query_param("market", "BTC-USD"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([market_json("BTC-USD")]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .and(query_param("market", "NOPE-USD"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([]))))
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+
+    let name: MarketName = "BTC-USD".parse().unwrap();
+    let market = client.public().get_market(&name).await.unwrap();
+    assert_eq!(name, market.name.as_str());
+    assert_eq!(market.synthetic_asset_id(), "0x4254432d3600000000000000000000");
+
+    match client.public().get_market("NOPE-USD").await {
+        Err(ExtendedError::Api { code, message }) => {
+            assert_eq!(code, "404");
+            assert!(message.contains("NOPE-USD"), "unexpected message: {message}");
+        }
+        other => panic!("unexpected result: {:?}", other.map(|m| m.name)),
+    }
+}
+
+#[tokio::test]
+async fn test_market_cache_fetches_once_within_ttl() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(ok(json!([market_json("BTC-USD"), market_json("ETH-USD")]))),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+
+    let (btc, eth) = tokio::join!(client.market("BTC-USD"), client.market("ETH-USD"));
+    assert_eq!(btc.unwrap().name, "BTC-USD");
+    assert_eq!(eth.unwrap().name, "ETH-USD");
+    assert_eq!(client.market("BTC-USD").await.unwrap().name, "BTC-USD");
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+
+    let markets = client.refresh_markets().await.unwrap();
+    assert_eq!(markets.len(), 2);
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_market_cache_refetches_once_for_unknown_market() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([market_json("BTC-USD")]))))
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    assert!(client.market("BTC-USD").await.is_ok());
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+
+    for _ in 0..10 {
+        let err = client.market("BTC-USDT").await.unwrap_err();
+        assert!(err.is_not_found(), "{:?}", err);
+    }
+    // One refetch for the miss, then the name is remembered as unknown
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+
+    // Other names still trigger their own refetch
+    assert!(client.market("ETH-USD").await.is_err());
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_get_all_market_stats_names_each_market() {
+    let server = MockServer::start().await;
+
+    let mut eth = market_json("ETH-USD");
+    eth["marketStats"]["markPrice"] = json!("3500.5");
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(ok(json!([eth, market_json("BTC-USD")]))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let stats = client.public().get_all_market_stats().await.unwrap();
+
+    assert_eq!(stats.len(), 2);
+    assert_eq!(stats[0].market.as_deref(), Some("BTC-USD"));
+    assert_eq!(stats[0].mark_price, dec!(100000));
+    assert_eq!(stats[1].market.as_deref(), Some("ETH-USD"));
+    assert_eq!(stats[1].mark_price, dec!(3500.5));
+}
+
+#[tokio::test]
+async fn test_get_liquidations() {
+    let server = MockServer::start().await;
+    let liquidations = json!([
+        { "market": "BTC-USD", "side": "SELL", "qty": "0.5", "price": "94000.5",
+          "timestamp": 1_700_000_002_000i64 },
+        { "market": "BTC-USD", "side": "BUY", "size": "1.2", "price": "95100",
+          "timestamp": 1_700_000_001_000i64 }
+    ]);
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets/BTC-USD/liquidations"))
+        .and(query_param("limit", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(liquidations.clone())))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets/BTC-USD/liquidations"))
+        .and(query_param_is_missing("limit"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let found = client.public().get_liquidations("BTC-USD", Some(2)).await.unwrap();
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].market, "BTC-USD");
+    assert_eq!(found[0].side, OrderSide::Sell);
+    assert_eq!(found[0].size, dec!(0.5));
+    assert_eq!(found[0].price, dec!(94000.5));
+    assert_eq!(found[0].timestamp, 1_700_000_002_000);
+    assert_eq!(found[0].notional(), dec!(47000.25));
+    assert_eq!(found[1].side, OrderSide::Buy);
+    assert_eq!(found[1].size, dec!(1.2));
+
+    assert!(client.public().get_liquidations("BTC-USD", None).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_get_candles_range_stitches_windows() {
+    const MINUTE: i64 = 60_000;
+    let start = 1_700_000_040_000;
+    let candle = |minute: i64| {
+        json!({
+            "timestamp": start + minute * MINUTE,
+            "open": "100", "high": "101", "low": "99", "close": "100.5", "volume": "2"
+        })
+    };
+
+    let server = MockServer::start().await;
+    // Windows of 1000 one-minute candles share their boundary candle. The first comes
+    // back newest first, the second has a gap, and the third is empty apart from the end
+    for (window_start, candles) in [
+        (0, vec![candle(999), candle(1), candle(0)]),
+        (999, vec![candle(999), candle(1500)]),
+        (1998, vec![candle(2500)]),
+    ] {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/info/candles/BTC-USD/trades/PT1M"))
+            .and(query_param("startTime", (start + window_start * MINUTE).to_string()))
+            .and(query_param("limit", "1000"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!(candles))))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let end = start + 2500 * MINUTE;
+    let candles = client
+        .public()
+        .get_candles_range("BTC-USD", CandleType::Trades, TimeInterval::OneMinute, start, end)
+        .await
+        .unwrap();
+
+    let minutes: Vec<i64> = candles.iter().map(|c| (c.timestamp - start) / MINUTE).collect();
+    assert_eq!(minutes, [0, 1, 999, 1500, 2500]);
+    assert_eq!(candles[0].close, dec!(100.5));
+
+    let reversed = client
+        .public()
+        .get_candles_range("BTC-USD", CandleType::Trades, TimeInterval::OneMinute, end, start)
+        .await;
+    assert!(matches!(reversed, Err(ExtendedError::InvalidParameter(_))));
+}
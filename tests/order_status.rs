@@ -0,0 +1,228 @@
+//! Tests of order status polling and cancel confirmation against a mock Extended server.
+
+mod common;
+
+use std::time::Duration;
+
+use extended_rust_sdk::error::ExtendedError;
+use extended_rust_sdk::models::OrderStatus;
+use extended_rust_sdk::TradingClient;
+use rust_decimal_macros::dec;
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use common::{mock_config, ok, test_account};
+
+const POLL: Duration = Duration::from_millis(10);
+
+fn order_json(status: &str, filled: &str) -> Value {
+    json!({
+        "id": 1001,
+        "market": "BTC-USD",
+        "side": "BUY",
+        "type": "LIMIT",
+        "status": status,
+        "price": "95000",
+        "qty": "0.02",
+        "filledQty": filled
+    })
+}
+
+/// Mount responses for `user/orders/1001`, served once each in order; the last repeats.
+async fn mount_order_responses(server: &MockServer, responses: Vec<ResponseTemplate>) {
+    let last = responses.len() - 1;
+    for (i, response) in responses.into_iter().enumerate() {
+        let mock = Mock::given(method("GET"))
+            .and(path("/api/v1/user/orders/1001"))
+            .respond_with(response);
+        let mock = if i < last { mock.up_to_n_times(1) } else { mock };
+        mock.mount(server).await;
+    }
+}
+
+#[tokio::test]
+async fn test_wait_for_order_retries_not_found_until_filled() {
+    let server = MockServer::start().await;
+    mount_order_responses(
+        &server,
+        vec![
+            // Just placed: not visible yet
+            ResponseTemplate::new(404),
+            ResponseTemplate::new(200).set_body_json(ok(order_json("OPEN", "0"))),
+            ResponseTemplate::new(200).set_body_json(ok(order_json("FILLED", "0.02"))),
+        ],
+    )
+    .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let order = client
+        .private()
+        .wait_for_order("1001", POLL, Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert_eq!(order.status, OrderStatus::Filled);
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_wait_for_fill_returns_on_partial_fill() {
+    let server = MockServer::start().await;
+    mount_order_responses(
+        &server,
+        vec![
+            ResponseTemplate::new(200).set_body_json(ok(order_json("OPEN", "0"))),
+            ResponseTemplate::new(200).set_body_json(ok(order_json("PARTIALLY_FILLED", "0.01"))),
+        ],
+    )
+    .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let order = client
+        .private()
+        .wait_for_fill("1001", dec!(0.01), POLL, Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert_eq!(order.status, OrderStatus::PartiallyFilled);
+    assert_eq!(order.filled_quantity, Some(dec!(0.01)));
+}
+
+#[tokio::test]
+async fn test_wait_for_order_gives_up() {
+    let server = MockServer::start().await;
+    mount_order_responses(&server, vec![ResponseTemplate::new(404)]).await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let result = client
+        .private()
+        .wait_for_order("1001", POLL, Duration::from_secs(5))
+        .await;
+    // The initial attempt plus three retries, then the error is returned
+    assert!(matches!(result, Err(ref e) if e.is_not_found()), "{:?}", result);
+    assert_eq!(server.received_requests().await.unwrap().len(), 4);
+
+    server.reset().await;
+    mount_order_responses(
+        &server,
+        vec![ResponseTemplate::new(200).set_body_json(ok(order_json("OPEN", "0")))],
+    )
+    .await;
+    let result = client
+        .private()
+        .wait_for_order("1001", POLL, Duration::from_millis(50))
+        .await;
+    assert!(matches!(result, Err(ExtendedError::Timeout(_))), "{:?}", result);
+}
+
+#[tokio::test]
+async fn test_cancel_all_and_confirm_ignores_new_orders() {
+    let server = MockServer::start().await;
+    let open = |ids: &[u64]| {
+        let orders: Vec<Value> = ids
+            .iter()
+            .map(|id| {
+                let mut order = order_json("OPEN", "0");
+                order["id"] = json!(id);
+                order
+            })
+            .collect();
+        ResponseTemplate::new(200).set_body_json(ok(json!(orders)))
+    };
+
+    // Snapshot, then 1 is gone and 3 was placed meanwhile, then only 3 is left
+    for (i, response) in [open(&[1, 2]), open(&[2, 3]), open(&[3])].into_iter().enumerate() {
+        let mock = Mock::given(method("GET"))
+            .and(path("/api/v1/user/orders"))
+            .and(query_param("market", "BTC-USD"))
+            .respond_with(response);
+        let mock = if i < 2 { mock.up_to_n_times(1) } else { mock };
+        mock.mount(&server).await;
+    }
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order/massCancel"))
+        .and(body_json(json!({ "market": "BTC-USD" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!({ "cancelledCount": 2 }))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let cancelled = client
+        .private()
+        .cancel_all_and_confirm(Some("BTC-USD"), POLL, Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert_eq!(cancelled, ["1", "2"]);
+    // The snapshot plus two checks; order 3 staying open doesn't keep it polling
+    let gets = server
+        .received_requests()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|r| r.method.as_str() == "GET")
+        .count();
+    assert_eq!(gets, 3);
+}
+
+#[tokio::test]
+async fn test_cancel_all_and_confirm_gives_up() {
+    let server = MockServer::start().await;
+    let mut order = order_json("OPEN", "0");
+    order["id"] = json!(1);
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/orders"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([order]))))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order/massCancel"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!({ "cancelledCount": 1 }))))
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let result = client
+        .private()
+        .cancel_all_and_confirm(None::<&str>, POLL, Duration::from_millis(50))
+        .await;
+    assert!(matches!(result, Err(ExtendedError::Timeout(_))), "{:?}", result);
+}
+
+#[tokio::test]
+async fn test_order_avg_fill_price_flags_mismatch() {
+    let server = MockServer::start().await;
+    let mut order = order_json("FILLED", "0.02");
+    order["averagePrice"] = json!("95500");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/orders/1001"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(order)))
+        .mount(&server)
+        .await;
+    let fill = |id: &str, price: &str| {
+        json!({
+            "id": id, "orderId": "1001", "market": "BTC-USD", "side": "BUY",
+            "price": price, "quantity": "0.01", "timestamp": 1_700_000_000_000i64
+        })
+    };
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/trades"))
+        .and(query_param("orderId", "1001"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "status": "OK",
+            "data": [fill("1", "95000"), fill("2", "95200")],
+            "pagination": { "cursor": null, "count": 2 }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let avg = client.private().order_avg_fill_price("1001").await.unwrap();
+
+    assert_eq!(avg.computed, Some(dec!(95100)));
+    assert_eq!(avg.reported, Some(dec!(95500)));
+    assert!(avg.mismatch);
+    assert_eq!(avg.price(), Some(dec!(95100)));
+}
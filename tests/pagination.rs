@@ -0,0 +1,175 @@
+//! Tests for helpers that page through history endpoints.
+
+mod common;
+
+use extended_rust_sdk::models::GetTradesParams;
+use futures::StreamExt;
+use extended_rust_sdk::ReadOnlyClient;
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use common::{mock_config, API_KEY};
+
+fn trade_json(id: u32) -> Value {
+    json!({
+        "id": id.to_string(),
+        "orderId": "77",
+        "market": "BTC-USD",
+        "side": "BUY",
+        "price": "100000",
+        "quantity": "0.01",
+        "fee": "0.5",
+        "isMaker": id < 4,
+        "timestamp": 1_700_000_000_000i64 + id as i64
+    })
+}
+
+fn page(ids: std::ops::Range<u32>, cursor: Option<i64>) -> Value {
+    let data: Vec<Value> = ids.clone().map(trade_json).collect();
+    json!({
+        "status": "OK",
+        "data": data,
+        "pagination": { "cursor": cursor, "count": ids.len() }
+    })
+}
+
+async fn mount_trade_pages(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/trades"))
+        .and(query_param("cursor", "3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(3..6, Some(6))))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/trades"))
+        .and(query_param("cursor", "6"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(6..8, None)))
+        .mount(server)
+        .await;
+    // First page (no cursor) - mounted last so the cursor matchers take priority
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/trades"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(0..3, Some(3))))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_get_all_trades_until_exhausted() {
+    let server = MockServer::start().await;
+    mount_trade_pages(&server).await;
+
+    let client = ReadOnlyClient::new(mock_config(&server), API_KEY).unwrap();
+    let trades = client.private().get_all_trades(None, 100).await.unwrap();
+
+    let ids: Vec<&str> = trades.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, ["0", "1", "2", "3", "4", "5", "6", "7"]);
+}
+
+#[tokio::test]
+async fn test_get_all_trades_respects_cap() {
+    let server = MockServer::start().await;
+    mount_trade_pages(&server).await;
+
+    let client = ReadOnlyClient::new(mock_config(&server), API_KEY).unwrap();
+    let params = GetTradesParams {
+        limit: Some(3),
+        ..Default::default()
+    };
+    let trades = client.private().get_all_trades(Some(params), 4).await.unwrap();
+
+    assert_eq!(trades.len(), 4);
+    // Only the first two pages were needed to reach the cap
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_trades_stream_follows_cursor_and_keeps_filters() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/trades"))
+        .and(query_param("cursor", "3"))
+        .and(query_param("market", "BTC-USD"))
+        .and(query_param("limit", "3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(3..5, None)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/trades"))
+        .and(query_param("market", "BTC-USD"))
+        .and(query_param("limit", "3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(0..3, Some(3))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = ReadOnlyClient::new(mock_config(&server), API_KEY).unwrap();
+    let params = GetTradesParams {
+        market: Some("BTC-USD".to_string()),
+        limit: Some(3),
+        ..Default::default()
+    };
+    let trades: Vec<_> = client.private().trades_stream(Some(params)).collect().await;
+
+    let ids: Vec<String> = trades.into_iter().map(|t| t.unwrap().id).collect();
+    assert_eq!(ids, ["0", "1", "2", "3", "4"]);
+}
+
+fn filled_orders_page(ids: std::ops::Range<u32>, cursor: Option<i64>) -> Value {
+    let data: Vec<Value> = ids
+        .clone()
+        .map(|id| {
+            json!({
+                "id": id,
+                "market": "ETH-USD",
+                "side": "SELL",
+                "type": "LIMIT",
+                "status": "FILLED",
+                "price": "3500",
+                "qty": "1",
+                "filledQty": "1"
+            })
+        })
+        .collect();
+    json!({
+        "status": "OK",
+        "data": data,
+        "pagination": { "cursor": cursor, "count": ids.len() }
+    })
+}
+
+#[tokio::test]
+async fn test_get_filled_orders_stops_mid_page() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/orders/history"))
+        .and(query_param("status", "FILLED"))
+        .and(query_param("market", "ETH-USD"))
+        .and(query_param("cursor", "3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(filled_orders_page(3..6, Some(6))))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/orders/history"))
+        .and(query_param("status", "FILLED"))
+        .and(query_param("market", "ETH-USD"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(filled_orders_page(0..3, Some(3))))
+        .expect(1..)
+        .mount(&server)
+        .await;
+
+    let client = ReadOnlyClient::new(mock_config(&server), API_KEY).unwrap();
+    let api = client.private();
+
+    // Two orders into the second page; the third page is never requested
+    let orders = api.get_filled_orders(Some("ETH-USD"), 5).await.unwrap();
+    let ids: Vec<&str> = orders.iter().map(|o| o.id.as_str()).collect();
+    assert_eq!(ids, ["0", "1", "2", "3", "4"]);
+
+    let orders = api.get_filled_orders(Some("ETH-USD"), 2).await.unwrap();
+    assert_eq!(orders.len(), 2);
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
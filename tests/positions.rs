@@ -0,0 +1,73 @@
+//! Tests of position queries against a mock Extended server.
+
+mod common;
+
+use extended_rust_sdk::models::{GetPositionsParams, PositionSide};
+use extended_rust_sdk::TradingClient;
+use rust_decimal_macros::dec;
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use common::{mock_config, ok, test_account};
+
+fn position_json(market: &str, side: &str, size: &str) -> Value {
+    json!({
+        "market": market,
+        "side": side,
+        "size": size,
+        "openPrice": "100000",
+        "markPrice": "101000",
+        "unrealisedPnl": "10",
+        "leverage": "5"
+    })
+}
+
+#[tokio::test]
+async fn test_get_positions_filters_by_side() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/positions"))
+        .and(query_param("side", "SHORT"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(ok(json!([position_json("ETH-USD", "SHORT", "2")]))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let params = GetPositionsParams::new().with_side(PositionSide::Short);
+    let shorts = client.private().get_positions(Some(params)).await.unwrap();
+
+    assert_eq!(shorts.len(), 1);
+    assert!(shorts[0].is_short());
+}
+
+#[tokio::test]
+async fn test_get_position_returns_none_without_a_position() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/positions"))
+        .and(query_param("market", "BTC-USD"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(ok(json!([position_json("BTC-USD", "LONG", "0.5")]))),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/positions"))
+        .and(query_param("market", "SOL-USD"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([]))))
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let position = client.private().get_position("BTC-USD").await.unwrap().unwrap();
+    assert_eq!(position.size, dec!(0.5));
+    assert!(position.is_long());
+
+    assert!(client.private().get_position("SOL-USD").await.unwrap().is_none());
+}
@@ -0,0 +1,36 @@
+//! Routing REST requests through an HTTP proxy, with the mock server as the proxy.
+
+mod common;
+
+use extended_rust_sdk::client::HttpClient;
+use serde_json::{json, Value};
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use common::{mock_config, ok};
+
+#[tokio::test]
+async fn test_requests_go_through_proxy_with_credentials() {
+    let proxy = MockServer::start().await;
+
+    // base64("user:secret")
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .and(header("Proxy-Authorization", "Basic dXNlcjpzZWNyZXQ="))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([]))))
+        .expect(1)
+        .mount(&proxy)
+        .await;
+
+    let proxy_url = proxy.uri().replace("http://", "http://user:secret@");
+    let mut config = mock_config(&proxy).with_proxy(proxy_url);
+    // Unresolvable, so the request can only succeed via the proxy
+    config.api_base_url = "http://exchange.invalid".to_string();
+
+    let client = HttpClient::new(config).unwrap();
+    let resp: Value = client.get("info/markets").await.unwrap();
+    assert_eq!(resp["data"], json!([]));
+
+    let requests = proxy.received_requests().await.unwrap();
+    assert_eq!(requests[0].url.host_str(), Some("exchange.invalid"));
+}
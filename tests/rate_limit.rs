@@ -0,0 +1,71 @@
+//! Tests of client-side request pacing, on tokio's paused clock.
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use extended_rust_sdk::client::MockTransport;
+use extended_rust_sdk::config::testnet_config;
+use extended_rust_sdk::TradingClient;
+use futures::future::join_all;
+use serde_json::json;
+use tokio::time::Instant;
+
+use common::{ok, test_account};
+
+fn client(requests_per_second: u32, burst: u32) -> TradingClient {
+    let transport = MockTransport::new()
+        .with_json("user/order/1001", json!({ "status": "OK" }))
+        .with_json("user/balance", ok(json!({ "balance": "100", "equity": "100" })));
+    let config = testnet_config()
+        .with_transport(Arc::new(transport))
+        .with_rate_limit(requests_per_second, burst);
+    TradingClient::new(config, test_account()).unwrap()
+}
+
+async fn cancel_many(client: &TradingClient, count: usize) -> Duration {
+    let start = Instant::now();
+    let cancels = (0..count).map(|_| client.private().cancel_order("1001"));
+    for result in join_all(cancels).await {
+        result.unwrap();
+    }
+    start.elapsed()
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_order_requests_wait_for_the_rate_limit() {
+    // At 2 per second the first cancel goes out at once and the rest every half second
+    let client = client(2, 1);
+    let elapsed = cancel_many(&client, 5).await;
+    assert!(
+        elapsed >= Duration::from_millis(1990) && elapsed <= Duration::from_millis(2010),
+        "{:?}",
+        elapsed
+    );
+
+    // Reads aren't paced
+    let start = Instant::now();
+    for _ in 0..5 {
+        client.private().get_balance().await.unwrap();
+    }
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limit_allows_bursts_after_a_pause() {
+    // Three go out at once, then the other four every half second
+    let client = client(2, 3);
+    let elapsed = cancel_many(&client, 7).await;
+    assert!(
+        elapsed >= Duration::from_millis(1990) && elapsed <= Duration::from_millis(2010),
+        "{:?}",
+        elapsed
+    );
+
+    // A quiet period refills the bucket, but only up to the burst size
+    tokio::time::sleep(Duration::from_secs(10)).await;
+    assert_eq!(cancel_many(&client, 3).await, Duration::ZERO);
+    let elapsed = cancel_many(&client, 1).await;
+    assert!(elapsed >= Duration::from_millis(490), "{:?}", elapsed);
+}
@@ -0,0 +1,65 @@
+//! Retry behaviour of the HTTP client against a mock server.
+
+mod common;
+
+use extended_rust_sdk::client::HttpClient;
+use extended_rust_sdk::error::ExtendedError;
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use common::{mock_config, ok};
+
+#[tokio::test]
+async fn test_get_retries_server_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = HttpClient::new(mock_config(&server)).unwrap();
+    let resp: Value = client.get("info/markets").await.unwrap();
+    assert_eq!(resp["data"], json!([]));
+}
+
+#[tokio::test]
+async fn test_get_gives_up_after_max_attempts() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let config = mock_config(&server);
+    let retry = config.retry.clone().with_max_attempts(2);
+    let client = HttpClient::new(config.with_retry(retry)).unwrap();
+    let err = client.get::<Value>("info/markets").await.unwrap_err();
+    assert!(matches!(err, ExtendedError::Api { ref code, .. } if code == "503"));
+}
+
+#[tokio::test]
+async fn test_post_is_not_retried_on_server_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = HttpClient::new(mock_config(&server)).unwrap();
+    let result = client.post::<Value, _>("user/order", &json!({})).await;
+    assert!(result.is_err());
+}
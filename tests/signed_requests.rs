@@ -0,0 +1,564 @@
+//! End-to-end tests of the HTTP + signing path against a mock Extended server.
+
+mod common;
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use extended_rust_sdk::models::{AutoCorrect, NonceGenerator, OrderBuilder, OrderSide};
+use extended_rust_sdk::signing::{
+    derive_stark_key_from_eth_key, sign_order, StarkSigner, MAINNET_ONBOARDING_DOMAIN,
+};
+use extended_rust_sdk::error::ExtendedError;
+use extended_rust_sdk::{ReduceOnlyCheck, TradingClient};
+use rust_decimal_macros::dec;
+use serde_json::{json, Value};
+use starknet::core::types::Felt;
+use starknet_crypto::pedersen_hash;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use common::{market_json, mock_config, ok, test_account, API_KEY, PRIVATE_KEY, VAULT_ID};
+
+#[tokio::test]
+async fn test_create_order_sends_signed_payload() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([market_json("BTC-USD")]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order"))
+        .and(header("X-Api-Key", API_KEY))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(ok(json!({ "id": 1001, "externalId": "ext-1" }))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let config = mock_config(&server);
+    let client = TradingClient::new(config.clone(), test_account()).unwrap();
+    let signer = StarkSigner::from_hex(PRIVATE_KEY).unwrap();
+
+    let markets = client.public().get_markets().await.unwrap();
+    let market = markets.get("BTC-USD").expect("market in canned payload");
+
+    let order = OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000), dec!(0.01), true, false)
+        .nonce(42)
+        .expiry(1_700_000_000_000)
+        .build();
+    let signed = sign_order(
+        order,
+        &signer,
+        VAULT_ID,
+        market.synthetic_asset_id(),
+        market.synthetic_resolution(),
+        &config.starknet_domain,
+    )
+    .unwrap();
+    let expected_body = serde_json::to_value(&signed).unwrap();
+
+    let placed = client.private().create_order(signed).await.unwrap();
+    assert_eq!(placed.id, "1001");
+    assert_eq!(placed.external_id, "ext-1");
+
+    let requests = server.received_requests().await.unwrap();
+    let order_request = requests
+        .iter()
+        .find(|r| r.url.path() == "/api/v1/user/order")
+        .expect("order request sent");
+    let body: Value = order_request.body_json().unwrap();
+
+    // The wire payload is exactly the signed request
+    assert_eq!(body, expected_body);
+
+    // Order id is the decimal order hash
+    let id = body["id"].as_str().unwrap();
+    assert!(!id.is_empty() && id.chars().all(|c| c.is_ascii_digit()));
+
+    // Settlement carries a hex signature, the account's stark key and the vault
+    let settlement = &body["settlement"];
+    assert!(settlement["signature"]["r"].as_str().unwrap().starts_with("0x"));
+    assert!(settlement["signature"]["s"].as_str().unwrap().starts_with("0x"));
+    assert_eq!(settlement["starkKey"], json!(signer.public_key_hex()));
+    assert_eq!(settlement["collateralPosition"], json!(VAULT_ID));
+
+    // Field names and decimal-as-string encoding match the API
+    assert_eq!(body["market"], "BTC-USD");
+    assert_eq!(body["side"], "BUY");
+    assert_eq!(body["type"], "LIMIT");
+    assert_eq!(body["qty"], "0.01");
+    assert_eq!(body["price"], "95000");
+    assert_eq!(body["nonce"], "42");
+    assert_eq!(body["postOnly"], true);
+    assert_eq!(body["timeInForce"], "GTT");
+    assert_eq!(body["expiryEpochMillis"], 1_700_000_000_000i64);
+    assert!(body.get("cancelId").is_none());
+    assert_eq!(body["debuggingAmounts"]["syntheticAmount"], "10000");
+    assert_eq!(body["debuggingAmounts"]["collateralAmount"], "-950000000");
+}
+
+#[tokio::test]
+async fn test_api_error_is_mapped() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/balance"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "status": "ERROR",
+            "error": { "code": 1100, "message": "Invalid API key" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let err = client.private().get_balance().await.unwrap_err();
+    assert!(
+        matches!(err, extended_rust_sdk::error::ExtendedError::Authentication(ref m) if m == "Invalid API key"),
+        "unexpected error: {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn test_auto_correct_resubmits_once_after_validation_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "status": "ERROR",
+            "error": { "code": 1125, "message": "Invalid price precision" }
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(ok(json!({ "id": 1002, "externalId": "ext-2" }))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let market: extended_rust_sdk::models::Market =
+        serde_json::from_value(market_json("BTC-USD")).unwrap();
+
+    let order =
+        OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000.7), dec!(0.000012), false, false)
+            .nonce(43)
+            .build();
+    let placed = client
+        .create_order_auto_correct(order, &market, AutoCorrect::RoundingAndMinSize)
+        .await
+        .unwrap();
+    assert_eq!(placed.id, "1002");
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 2);
+    let first: Value = requests[0].body_json().unwrap();
+    let second: Value = requests[1].body_json().unwrap();
+    assert_eq!(first["price"], "95000.7");
+    assert_eq!(second["price"], "95000");
+    // Raised to the 0.0001 minimum order size
+    assert_eq!(second["qty"], "0.00010");
+    assert_ne!(first["id"], second["id"]);
+}
+
+#[tokio::test]
+async fn test_auto_correct_only_rounds_by_default() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "status": "ERROR",
+            "error": { "code": 1125, "message": "Invalid quantity precision" }
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let market: extended_rust_sdk::models::Market =
+        serde_json::from_value(market_json("BTC-USD")).unwrap();
+
+    let order =
+        OrderBuilder::limit("BTC-USD", OrderSide::Sell, dec!(95000), dec!(0.000012), false, false)
+            .nonce(44)
+            .build();
+    let result = client
+        .private()
+        .create_order_auto_correct(order, &market, AutoCorrect::default(), |order| {
+            client.sign_order(order, &market)
+        })
+        .await;
+    assert!(matches!(result, Err(ExtendedError::OrderValidation(_))), "{:?}", result);
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 2);
+    let second: Value = requests[1].body_json().unwrap();
+    // Rounded to the step size but left below the 0.0001 minimum
+    assert_eq!(second["qty"], "0.00001");
+}
+
+#[tokio::test]
+async fn test_auto_correct_does_not_resubmit_a_duplicate_external_id() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "status": "ERROR",
+            "error": { "code": 1125, "message": "Invalid price precision" }
+        })))
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account())
+        .unwrap()
+        .with_external_id_tracking();
+    let market: extended_rust_sdk::models::Market =
+        serde_json::from_value(market_json("BTC-USD")).unwrap();
+    let order = |nonce| {
+        let mut order =
+            OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000.7), dec!(0.01), false, false)
+                .nonce(nonce)
+                .build();
+        order.id = "dup-1".to_string();
+        order
+    };
+
+    let first = client.sign_order(order(45), &market).unwrap();
+    assert!(client.private().create_order(first).await.is_err());
+
+    let result = client
+        .create_order_auto_correct(order(46), &market, AutoCorrect::Rounding)
+        .await;
+    assert!(
+        matches!(result, Err(ExtendedError::OrderValidation(ref m)) if m.contains("Duplicate")),
+        "{:?}",
+        result
+    );
+    // Only the first order was posted, and its ID is still tracked
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    assert!(client.private().check_external_id("dup-1").is_err());
+}
+
+#[tokio::test]
+async fn test_replace_order_sends_cancel_id() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(ok(json!({ "id": 1003, "externalId": "ext-new" }))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let market: extended_rust_sdk::models::Market =
+        serde_json::from_value(market_json("BTC-USD")).unwrap();
+
+    let order =
+        OrderBuilder::limit("BTC-USD", OrderSide::Sell, dec!(96000), dec!(0.01), true, false)
+            .nonce(44)
+            .build();
+    let signed = client.sign_order(order, &market).unwrap();
+    let placed = client.private().replace_order("ext-old", signed).await.unwrap();
+    assert_eq!(placed.external_id, "ext-new");
+
+    let requests = server.received_requests().await.unwrap();
+    let body: Value = requests[0].body_json().unwrap();
+    assert_eq!(body["cancelId"], "ext-old");
+    assert_eq!(body["price"], "96000");
+}
+
+/// Discounted fee tier for BTC-USD, fetched once per client.
+async fn mount_fee_tier(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/fees"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([
+            { "market": "BTC-USD", "makerFeeRate": "0.0002", "takerFeeRate": "0.0003" }
+        ]))))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_place_order_signs_with_cached_market() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([market_json("BTC-USD")]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_fee_tier(&server).await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order"))
+        .and(header("X-Api-Key", API_KEY))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(ok(json!({ "id": 1004, "externalId": "ext-4" }))),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let config = mock_config(&server);
+    let client = TradingClient::new(config.clone(), test_account()).unwrap();
+    let signer = StarkSigner::from_hex(PRIVATE_KEY).unwrap();
+
+    let order = OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000), dec!(0.01), true, false)
+        .nonce(45)
+        .expiry(1_700_000_000_000);
+    let placed = client.place_order(order.clone()).await.unwrap();
+    assert_eq!(placed.id, "1004");
+    client.place_order(order.clone().nonce(46)).await.unwrap();
+
+    // Same payload as signing by hand with the market's L2 config
+    let market: extended_rust_sdk::models::Market =
+        serde_json::from_value(market_json("BTC-USD")).unwrap();
+    let expected = sign_order(
+        order.clone().fee(dec!(0.0002)).build(),
+        &signer,
+        VAULT_ID,
+        market.synthetic_asset_id(),
+        market.synthetic_resolution(),
+        &config.starknet_domain,
+    )
+    .unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let orders: Vec<Value> = requests
+        .iter()
+        .filter(|r| r.url.path() == "/api/v1/user/order")
+        .map(|r| r.body_json().unwrap())
+        .collect();
+    assert_eq!(orders.len(), 2);
+    assert_eq!(orders[0], serde_json::to_value(&expected).unwrap());
+    assert_eq!(orders[1]["nonce"], "46");
+}
+
+#[tokio::test]
+async fn test_place_order_signs_fee_from_tier() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([market_json("BTC-USD")]))))
+        .mount(&server)
+        .await;
+    mount_fee_tier(&server).await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(ok(json!({ "id": 1005, "externalId": "ext-5" }))),
+        )
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    // Post-only rests on the book, so it pays the maker rate
+    let order = |post_only, nonce| {
+        OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000), dec!(0.01), post_only, false)
+            .nonce(nonce)
+    };
+    client.place_order(order(true, 47)).await.unwrap();
+    client.place_order(order(false, 48)).await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let orders: Vec<Value> = requests
+        .iter()
+        .filter(|r| r.url.path() == "/api/v1/user/order")
+        .map(|r| r.body_json().unwrap())
+        .collect();
+    // 950 USD notional in 1e-6 collateral units, instead of 475000 at DEFAULT_FEE_RATE
+    assert_eq!(orders[0]["fee"], "0.0002");
+    assert_eq!(orders[0]["debuggingAmounts"]["feeAmount"], "190000");
+    assert_eq!(orders[1]["fee"], "0.0003");
+    assert_eq!(orders[1]["debuggingAmounts"]["feeAmount"], "285000");
+}
+
+#[tokio::test]
+async fn test_place_order_checks_reduce_only_against_position() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([market_json("BTC-USD")]))))
+        .mount(&server)
+        .await;
+    mount_fee_tier(&server).await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/positions"))
+        .and(query_param("market", "BTC-USD"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([{
+            "market": "BTC-USD",
+            "side": "LONG",
+            "size": "0.02",
+            "openPrice": "94000",
+            "markPrice": "95000",
+            "unrealisedPnl": "20",
+            "leverage": "10"
+        }]))))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(ok(json!({ "id": 1006, "externalId": "ext-6" }))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let order = |side, quantity| {
+        OrderBuilder::limit("BTC-USD", side, dec!(95000), quantity, false, true).nonce(49)
+    };
+
+    // Buying would grow the long position, so nothing is submitted
+    let client = TradingClient::new(mock_config(&server), test_account())
+        .unwrap()
+        .with_reduce_only_check(ReduceOnlyCheck::Reject);
+    let result = client.place_order(order(OrderSide::Buy, dec!(0.01))).await;
+    assert!(matches!(result, Err(ExtendedError::OrderValidation(_))));
+    let result = client.place_order(order(OrderSide::Sell, dec!(0.05))).await;
+    assert!(matches!(result, Err(ExtendedError::OrderValidation(_))));
+
+    // Capped to the position size and submitted
+    let client = TradingClient::new(mock_config(&server), test_account())
+        .unwrap()
+        .with_reduce_only_check(ReduceOnlyCheck::Cap);
+    client.place_order(order(OrderSide::Sell, dec!(0.05))).await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let placed: Value = requests
+        .iter()
+        .find(|r| r.url.path() == "/api/v1/user/order")
+        .unwrap()
+        .body_json()
+        .unwrap();
+    assert_eq!(placed["qty"], "0.02");
+    assert_eq!(placed["reduceOnly"], true);
+}
+
+/// Counter nonces, standing in for e.g. a store shared between processes.
+#[derive(Debug)]
+struct CounterNonces(AtomicU64);
+
+impl NonceGenerator for CounterNonces {
+    fn next(&self) -> u64 {
+        self.0.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+#[tokio::test]
+async fn test_place_order_uses_custom_nonce_generator() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([market_json("BTC-USD")]))))
+        .mount(&server)
+        .await;
+    mount_fee_tier(&server).await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/user/order"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(ok(json!({ "id": 1007, "externalId": "ext-7" }))),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account())
+        .unwrap()
+        .with_nonce_generator(Arc::new(CounterNonces(AtomicU64::new(7))));
+    let order =
+        OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000), dec!(0.01), true, false);
+    client.place_order(order.clone()).await.unwrap();
+    client.place_order(order).await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let nonces: Vec<Value> = requests
+        .iter()
+        .filter(|r| r.url.path() == "/api/v1/user/order")
+        .map(|r| r.body_json::<Value>().unwrap()["nonce"].clone())
+        .collect();
+    assert_eq!(nonces, vec![json!("7"), json!("8")]);
+}
+
+#[tokio::test]
+async fn test_register_stark_key_sends_onboarding_payload() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/auth/onboard"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!({
+            "accountId": 7, "accountIndex": 0, "status": "ACTIVE", "l2Vault": "10001"
+        }))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let eth_key = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    let derived = derive_stark_key_from_eth_key(eth_key, 0, MAINNET_ONBOARDING_DOMAIN).unwrap();
+
+    let info = client.private().register_stark_key(&derived).await.unwrap();
+    assert_eq!(info.account_id, Some(7));
+
+    let requests = server.received_requests().await.unwrap();
+    let body: Value = serde_json::from_slice(&requests[0].body).unwrap();
+    let keys: Vec<_> = body.as_object().unwrap().keys().cloned().collect();
+    assert_eq!(keys, ["accountCreation", "l1Signature", "l2Key", "l2Signature"]);
+    assert_eq!(body["l1Signature"], derived.eth_signature.as_str());
+    assert_eq!(body["l2Key"], derived.signer.public_key_hex().as_str());
+    assert_eq!(
+        body["accountCreation"],
+        json!({ "accountIndex": 0, "wallet": derived.eth_address, "tosAccepted": true })
+    );
+
+    // The L2 signature covers the wallet address and the Stark public key
+    let felt = |v: &Value| Felt::from_hex(v.as_str().unwrap()).unwrap();
+    let wallet = Felt::from_hex(&derived.eth_address).unwrap();
+    let hash = pedersen_hash(&wallet, derived.signer.public_key());
+    assert!(derived.signer.verify(
+        &hash,
+        &felt(&body["l2Signature"]["r"]),
+        &felt(&body["l2Signature"]["s"])
+    ));
+}
+
+#[tokio::test]
+async fn test_register_stark_key_rejects_foreign_key() {
+    let server = MockServer::start().await;
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+
+    let eth_key = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    let mut derived = derive_stark_key_from_eth_key(eth_key, 0, MAINNET_ONBOARDING_DOMAIN).unwrap();
+    derived.signer = StarkSigner::from_hex(PRIVATE_KEY).unwrap();
+
+    let result = client.private().register_stark_key(&derived).await;
+    assert!(matches!(result, Err(ExtendedError::Signing(_))), "{:?}", result);
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
@@ -0,0 +1,67 @@
+//! Request timeouts and connection failures of the HTTP client.
+
+mod common;
+
+use std::error::Error;
+use std::time::Duration;
+
+use extended_rust_sdk::client::HttpClient;
+use extended_rust_sdk::config::RetryConfig;
+use extended_rust_sdk::error::ExtendedError;
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use common::{mock_config, ok};
+
+#[tokio::test]
+async fn test_slow_response_times_out() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(ok(json!([])))
+                .set_delay(Duration::from_secs(2)),
+        )
+        .mount(&server)
+        .await;
+
+    let config = mock_config(&server)
+        .with_retry(RetryConfig::disabled())
+        .with_timeout(Duration::from_millis(100));
+    let client = HttpClient::new(config).unwrap();
+
+    let started = std::time::Instant::now();
+    let err = client.get::<Value>("info/markets").await.unwrap_err();
+    assert!(
+        matches!(err, ExtendedError::RequestTimeout(ref e) if e.is_timeout()),
+        "unexpected error: {err:?}"
+    );
+    assert!(started.elapsed() < Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_request_timeout_keeps_its_source() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(2)))
+        .mount(&server)
+        .await;
+
+    let client = reqwest::Client::builder().timeout(Duration::from_millis(50)).build().unwrap();
+    let err = ExtendedError::from(client.get(server.uri()).send().await.unwrap_err());
+    let source = err.source().and_then(|e| e.downcast_ref::<reqwest::Error>());
+    assert!(source.is_some_and(reqwest::Error::is_timeout), "{:?}", err);
+}
+
+#[tokio::test]
+async fn test_refused_connection_maps_to_connection_error() {
+    // Connecting to port 0 is always refused
+    let mut config = mock_config(&MockServer::start().await).with_retry(RetryConfig::disabled());
+    config.api_base_url = "http://127.0.0.1:0".to_string();
+    let client = HttpClient::new(config).unwrap();
+
+    let err = client.get::<Value>("info/markets").await.unwrap_err();
+    assert!(matches!(err, ExtendedError::Connection(ref e) if e.is_connect()), "{:?}", err);
+}
@@ -0,0 +1,106 @@
+//! Tests of request tracing (`tracing` feature) against a mock Extended server.
+
+#![cfg(feature = "tracing")]
+
+mod common;
+
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use extended_rust_sdk::TradingClient;
+use serde_json::json;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use common::{mock_config, ok, test_account, API_KEY};
+
+/// Subscriber that records the SDK's spans and events as lines of `name key=value ...`.
+#[derive(Default)]
+struct Recorder {
+    lines: Arc<Mutex<Vec<String>>>,
+    next_id: AtomicU64,
+}
+
+#[derive(Default)]
+struct Fields(String);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.push_str(&format!(" {}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target().starts_with("extended_rust_sdk")
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields::default();
+        span.record(&mut fields);
+        let metadata = span.metadata();
+        let line = format!("span {} {}{}", metadata.level(), metadata.name(), fields.0);
+        self.lines.lock().unwrap().push(line);
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        let line = format!("event {}{}", event.metadata().level(), fields.0);
+        self.lines.lock().unwrap().push(line);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_requests_are_traced_without_the_api_key() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/balance"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!({
+            "balance": "100", "equity": "105"
+        }))))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/leverage"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "status": "ERROR",
+            "error": { "code": 1006, "message": "Invalid market" }
+        })))
+        .mount(&server)
+        .await;
+
+    let recorder = Recorder::default();
+    let lines = recorder.lines.clone();
+    let _guard = tracing::subscriber::set_default(recorder);
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    client.private().get_balance().await.unwrap();
+    assert!(client.private().get_leverage(None::<&str>).await.is_err());
+
+    let lines = lines.lock().unwrap();
+    let has = |needle: &str| lines.iter().any(|line| line.contains(needle));
+    assert!(
+        has(r#"span DEBUG http_request method=GET path="/api/v1/user/balance""#),
+        "{:#?}",
+        lines
+    );
+    assert!(has("response received attempt=1 status=200 latency_ms="), "{:#?}", lines);
+    assert!(has("event WARN message=API error response status=400"), "{:#?}", lines);
+    assert!(has("Invalid market"), "{:#?}", lines);
+    assert!(!lines.iter().any(|line| line.contains(API_KEY)));
+}
@@ -0,0 +1,96 @@
+//! Tests of clients running over `MockTransport` instead of the network.
+
+mod common;
+
+use std::sync::Arc;
+
+use extended_rust_sdk::client::MockTransport;
+use extended_rust_sdk::config::testnet_config;
+use extended_rust_sdk::models::{MarketName, OrderBuilder, OrderSide};
+use extended_rust_sdk::TradingClient;
+use rust_decimal_macros::dec;
+use serde_json::json;
+
+use common::{market_json, ok, test_account, API_KEY};
+
+#[tokio::test]
+async fn test_trading_client_over_mock_transport() {
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_json("info/markets", ok(json!([market_json("BTC-USD")])))
+            .with_json("user/positions", ok(json!([])))
+            .with_json("user/balance", ok(json!({ "balance": "250", "equity": "240.5" })))
+            .with_response(
+                "user/leverage",
+                400,
+                json!({
+                    "status": "ERROR",
+                    "error": { "code": 1006, "message": "Invalid market" }
+                }),
+            ),
+    );
+    let config = testnet_config().with_transport(transport.clone());
+    let client = TradingClient::new(config, test_account()).unwrap();
+
+    let market = client.market("BTC-USD").await.unwrap();
+    assert_eq!(market.name, "BTC-USD");
+    let balance = client.private().get_balance().await.unwrap();
+    assert_eq!(balance.equity, dec!(240.5));
+    assert!(client.private().get_positions(None).await.unwrap().is_empty());
+    let name: MarketName = "BTC-USD".parse().unwrap();
+    assert!(client.private().get_leverage(Some(&name)).await.is_err());
+
+    // Unmocked paths answer 404
+    let result = client.private().get_order("42").await;
+    assert!(matches!(result, Err(ref e) if e.is_not_found()), "{:?}", result);
+
+    let requests = transport.requests();
+    let paths: Vec<&str> = requests.iter().map(|r| r.url.path()).collect();
+    assert_eq!(
+        paths,
+        [
+            "/api/v1/info/markets",
+            "/api/v1/user/balance",
+            "/api/v1/user/positions",
+            "/api/v1/user/leverage",
+            "/api/v1/user/orders/42",
+        ]
+    );
+    assert_eq!(requests[3].url.query(), Some("market=BTC-USD"));
+    // Private requests carry the API key, which never shows up in Debug output
+    assert_eq!(requests[1].headers["X-Api-Key"], API_KEY);
+    assert!(requests[0].headers.get("X-Api-Key").is_none());
+    assert!(!format!("{:?}", requests[1]).contains(API_KEY));
+    assert!(!format!("{:?}", client).contains(API_KEY));
+}
+
+#[tokio::test]
+async fn test_mock_transport_records_order_bodies() {
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_json("info/markets", ok(json!([market_json("BTC-USD")])))
+            .with_json(
+                "user/fees",
+                ok(json!([{ "market": "BTC-USD", "makerFeeRate": "0", "takerFeeRate": "0.0005" }])),
+            )
+            .with_json("user/order", ok(json!({ "id": 1001, "externalId": "grid-1" }))),
+    );
+    let config = testnet_config().with_transport(transport.clone());
+    let client = TradingClient::new(config, test_account()).unwrap();
+
+    let order =
+        OrderBuilder::limit("BTC-USD", OrderSide::Buy, dec!(95000), dec!(0.01), false, false)
+            .external_id("grid-1");
+    let placed = client.place_order(order).await.unwrap();
+    let order_request = transport
+        .requests()
+        .into_iter()
+        .find(|r| r.url.path() == "/api/v1/user/order")
+        .expect("order was sent");
+    let body: serde_json::Value =
+        serde_json::from_str(order_request.body.as_deref().unwrap()).unwrap();
+    assert_eq!(body["id"], "grid-1");
+    assert_eq!(body["market"], "BTC-USD");
+    assert!(body["settlement"]["signature"].is_object());
+    assert_eq!(placed.id, "1001");
+}
@@ -0,0 +1,160 @@
+//! Tests of withdrawal history, status polling and signing against a mock Extended server.
+
+mod common;
+
+use std::time::Duration;
+
+use extended_rust_sdk::config::testnet_config;
+use extended_rust_sdk::error::ExtendedError;
+use extended_rust_sdk::models::{CollateralConfig, PaginationParams, WithdrawalStatus};
+use extended_rust_sdk::signing::{sign_withdrawal, StarkSigner};
+use extended_rust_sdk::TradingClient;
+use rust_decimal_macros::dec;
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use common::{market_json, mock_config, ok, test_account, PRIVATE_KEY, VAULT_ID};
+
+fn withdrawal_json(id: &str, status: &str) -> Value {
+    json!({
+        "id": id,
+        "amount": "250.5",
+        "recipient": "0x0456",
+        "status": status,
+        "txHash": null,
+        "createdAt": 1700000000000i64,
+        "completedAt": null
+    })
+}
+
+#[tokio::test]
+async fn test_get_withdrawals_sends_pagination() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/withdrawals"))
+        .and(query_param("cursor", "42"))
+        .and(query_param("limit", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "status": "OK",
+            "data": [withdrawal_json("7", "COMPLETED"), withdrawal_json("6", "FAILED")],
+            "pagination": { "cursor": 6, "count": 2 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let page = client
+        .private()
+        .get_withdrawals(PaginationParams::new().with_cursor(42).with_limit(2))
+        .await
+        .unwrap();
+
+    assert_eq!(page.data.len(), 2);
+    assert_eq!(page.data[0].status, WithdrawalStatus::Completed);
+    assert_eq!(page.data[1].status, WithdrawalStatus::Failed);
+}
+
+#[tokio::test]
+async fn test_wait_for_withdrawal_polls_until_completed() {
+    let server = MockServer::start().await;
+
+    // Mocks are tried in mount order, so the first two polls see the in-flight states
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/withdrawals/7"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(ok(withdrawal_json("7", "PENDING"))),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/withdrawals/7"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(ok(withdrawal_json("7", "PROCESSING"))),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/withdrawals/7"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(ok(withdrawal_json("7", "COMPLETED"))),
+        )
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let withdrawal = client
+        .private()
+        .wait_for_withdrawal("7", Duration::from_millis(10), Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    assert_eq!(withdrawal.status, WithdrawalStatus::Completed);
+    assert_eq!(server.received_requests().await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_wait_for_withdrawal_times_out() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/user/withdrawals/7"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(ok(withdrawal_json("7", "PENDING"))),
+        )
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let result = client
+        .private()
+        .wait_for_withdrawal("7", Duration::from_millis(10), Duration::from_millis(50))
+        .await;
+
+    match result {
+        Err(ExtendedError::Timeout(msg)) => assert!(msg.contains("Pending")),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_withdrawals_are_signed_with_the_fetched_collateral_resolution() {
+    let server = MockServer::start().await;
+    let mut market = market_json("BTC-USD");
+    market["l2Config"]["collateralResolution"] = json!(1000);
+    Mock::given(method("GET"))
+        .and(path("/api/v1/info/markets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(ok(json!([market]))))
+        .mount(&server)
+        .await;
+
+    let client = TradingClient::new(mock_config(&server), test_account()).unwrap();
+    let collateral = client.public().get_collateral_config().await.unwrap();
+    assert_eq!(
+        collateral,
+        CollateralConfig {
+            asset_id: "0x1".to_string(),
+            resolution: 1000
+        }
+    );
+
+    let signer = StarkSigner::from_hex(PRIVATE_KEY).unwrap();
+    let domain = testnet_config().starknet_domain;
+    let sign = |collateral: &CollateralConfig| {
+        let (recipient, expiry) = ("0x0456", 1_700_000_000_000);
+        sign_withdrawal(dec!(12.5), recipient, 7, expiry, VAULT_ID, collateral, &signer, &domain)
+            .unwrap()
+            .signature
+            .r
+    };
+    let expected = CollateralConfig {
+        asset_id: "0x1".to_string(),
+        resolution: 1000,
+    };
+    assert_eq!(sign(&collateral), sign(&expected));
+    assert_ne!(sign(&collateral), sign(&CollateralConfig::default()));
+}